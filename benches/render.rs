@@ -0,0 +1,49 @@
+//! Benchmarks for rendering slices and tables of various sizes
+//!
+//! Run them with `cargo bench`.
+use borderrs::{styles, table::TableBuilder, BorderFormatter};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// A table of `rows` rows, where every cell is `height` lines tall
+fn table(rows: usize, height: usize) -> TableBuilder {
+    let mut table = TableBuilder::new();
+    table.header(["Id", "Name", "Description"]);
+    for i in 0..rows {
+        let description = vec![format!("Line of row {}", i); height].join("\n");
+        table.row([i.to_string(), format!("Row {}", i), description]);
+    }
+    table
+}
+
+fn slices(c: &mut Criterion) {
+    let mut group = c.benchmark_group("format_slice");
+    for height in [1, 10, 100] {
+        let slice: Vec<_> = (0..10)
+            .map(|i| vec![format!("entry {}", i); height].join("\n"))
+            .collect();
+        group.bench_with_input(BenchmarkId::from_parameter(height), &slice, |b, slice| {
+            b.iter(|| styles::THIN.format_slice(black_box(slice)))
+        });
+    }
+    group.finish();
+}
+
+fn tables(c: &mut Criterion) {
+    let mut group = c.benchmark_group("table_builder");
+    for rows in [10, 1_000, 10_000] {
+        let table = table(rows, 1);
+        group.bench_with_input(BenchmarkId::new("rows", rows), &table, |b, table| {
+            b.iter(|| black_box(table).build(&styles::THIN))
+        });
+    }
+    for height in [10, 100] {
+        let table = table(100, height);
+        group.bench_with_input(BenchmarkId::new("height", height), &table, |b, table| {
+            b.iter(|| black_box(table).build(&styles::THIN))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, slices, tables);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+//! Derive macros for [`borderrs`](https://docs.rs/borderrs).
+//!
+//! These are re-exported by `borderrs` when the `derive` feature is enabled, so this crate
+//! shouldn't need to be used directly.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+/// Derive `borderrs::table::ToRow` for a struct
+///
+/// Each field becomes a column, using the field name as the header and the [`Display`]
+/// implementation of the field as the cell.  Fields of tuple structs use their index as the
+/// header.
+///
+/// Fields can be configured using the `#[row(...)]` attribute:
+/// - `#[row(rename = "Header")]` uses `Header` as the header instead of the field name
+/// - `#[row(skip)]` leaves the field out of the table
+///
+/// [`Display`]: std::fmt::Display
+#[proc_macro_derive(ToRow, attributes(row))]
+pub fn derive_to_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match to_row(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Generate the `ToRow` implementation for `input`
+fn to_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "ToRow can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut headers = Vec::new();
+    let mut cells = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let mut header = None;
+        let mut skip = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("row")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    header = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip` or `rename = \"...\"`"))
+                }
+            })?;
+        }
+
+        if skip {
+            continue;
+        }
+
+        // Tuple struct fields don't have names, so we use their index instead
+        let member = match (&field.ident, fields) {
+            (Some(ident), _) => quote!(#ident),
+            (None, Fields::Unnamed(_)) => {
+                let index = syn::Index::from(i);
+                quote!(#index)
+            }
+            (None, _) => unreachable!("only tuple struct fields are unnamed"),
+        };
+        let header = header.unwrap_or_else(|| match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => i.to_string(),
+        });
+
+        headers.push(header);
+        cells.push(quote!(::borderrs::__private::ToString::to_string(&self.#member)));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::borderrs::table::ToRow for #name #ty_generics #where_clause {
+            fn headers() -> ::borderrs::__private::Vec<::borderrs::__private::String> {
+                ::borderrs::__private::vec![#(::borderrs::__private::String::from(#headers)),*]
+            }
+
+            fn to_row(&self) -> ::borderrs::__private::Vec<::borderrs::__private::String> {
+                ::borderrs::__private::vec![#(#cells),*]
+            }
+        }
+    })
+}
@@ -42,4 +42,11 @@ fn main() {
 
     println!("{}", THIN.format_display("hello"));
     println!("{}", THIN.format_debug("hello"));
+
+    let table = vec![
+        vec!["Name", "Age", "City"],
+        vec!["Jon", "38", "Chicago"],
+        vec!["Jake", "25"],
+    ];
+    println!("{}", THIN.format_table(&table));
 }
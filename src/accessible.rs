@@ -0,0 +1,248 @@
+//! Writing tables as plain lines of text for screen readers and other assistive technologies
+//!
+//! [`AccessibleStyle`] implements [`BorderFormatter`], so every `format_*` method can produce
+//! linear text instead of a character grid.  Since it is just another style, it can be picked at
+//! runtime in the same way as any other, such as with a
+//! [`DynBorderFormatter`](crate::dynamic::DynBorderFormatter):
+//! ```rust
+//! use borderrs::{dynamic::DynBorderFormatter, styles, BorderFormatter};
+//!
+//! let screen_reader = true;
+//! let style: Box<dyn DynBorderFormatter> = match screen_reader {
+//!     true => Box::new(styles::ACCESSIBLE),
+//!     false => Box::new(styles::THIN),
+//! };
+//! println!("{}", style.format_table_with_headers(&["a", "b"], &[vec![1, 2]]));
+//! ```
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "std")]
+use crate::table::EmptyBehavior;
+use crate::{
+    table::{BorderWidths, Layout, LayoutCell, TableBuilder},
+    tree::{self, Guides},
+    width, Alignment, BorderFormatter,
+};
+
+/// Format as plain lines of text, without any borders or padding
+///
+/// Tables with a header have a `Header: value` line for each cell, with a blank line between
+/// rows, and tables without one have a line for each row with its cells separated by commas.
+/// Empty cells are left out, cells that span multiple rows are repeated in each of them, cells
+/// with several lines have their lines indented below the header, and ANSI escape sequences are
+/// removed.  Slices are written with one item per line, and trees are written as nested lists.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, table::TableBuilder};
+/// let table = TableBuilder::new()
+///     .title("Staff")
+///     .header(["Name", "Age"])
+///     .row(["Jon", "38"])
+///     .row(["Jake", "25"])
+///     .build(&styles::ACCESSIBLE);
+///
+/// println!("{}", table);
+/// ```
+/// produces the output
+/// ```text
+/// Staff
+///
+/// Name: Jon
+/// Age: 38
+///
+/// Name: Jake
+/// Age: 25
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccessibleStyle;
+
+/// Get the lines that a table is written as
+fn layout_lines(layout: &Layout) -> Vec<String> {
+    let mut rows = layout.rows.iter();
+    let headers: Option<Vec<String>> = match layout.has_header {
+        true => rows
+            .next()
+            .map(|row| row.iter().map(|cell| cell_lines(cell).join(" ")).collect()),
+        false => None,
+    };
+
+    // The lines of the cell that covers each column from a row above
+    let mut spanning = vec![Vec::new(); layout.widths.len()];
+    let mut out = Vec::new();
+    for (i, row) in rows.enumerate() {
+        let mut cells = Vec::new();
+        for (col, cell) in row.iter().enumerate() {
+            if cell.span == 0 {
+                continue;
+            }
+            if !cell.continued {
+                spanning[col] = cell_lines(cell);
+            }
+            cells.push((col, spanning[col].clone()));
+        }
+        // Empty cells are left out, rather than leaving a gap between the commas
+        cells.retain(|(_, lines)| !lines.is_empty());
+
+        let Some(headers) = &headers else {
+            match cells.iter().all(|(_, lines)| lines.len() <= 1) {
+                true => out.push(
+                    cells
+                        .iter()
+                        .map(|(_, lines)| lines.join(""))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                false => out.extend(cells.iter().flat_map(|(_, lines)| lines.iter().cloned())),
+            }
+            continue;
+        };
+        if i > 0 {
+            out.push(String::new());
+        }
+        for (col, lines) in cells {
+            let header = headers.get(col).map_or("", String::as_str);
+            match lines.as_slice() {
+                [] => {}
+                [line] => out.push(format!("{}: {}", header, line)),
+                lines => {
+                    out.push(format!("{}:", header));
+                    out.extend(lines.iter().map(|line| format!("  {}", line)));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Get the lines of a cell without their padding and ANSI escape sequences, with the lines of a
+/// table nested inside of it
+fn cell_lines(cell: &LayoutCell) -> Vec<String> {
+    if let Some(nested) = &cell.nested {
+        return layout_lines(nested);
+    }
+    let lines: Vec<_> = cell
+        .lines
+        .iter()
+        .map(|line| width::strip_ansi(line).trim().to_string())
+        .collect();
+    match lines.iter().all(String::is_empty) {
+        true => Vec::new(),
+        false => lines,
+    }
+}
+
+impl BorderFormatter for AccessibleStyle {
+    fn write_slice_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        _alignment: Alignment,
+    ) -> fmt::Result {
+        for (i, item) in slice.iter().enumerate() {
+            if i > 0 {
+                w.write_char('\n')?;
+            }
+            w.write_str(&width::strip_ansi(&item.to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn write_table_builder(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        table: &TableBuilder,
+    ) -> fmt::Result {
+        if let Some(title) = &table.title {
+            writeln!(w, "{}", width::strip_ansi(title))?;
+            w.write_char('\n')?;
+        }
+        // Nothing is cut short to fit a width, since the lines don't have to line up
+        let table = table.unlimited();
+        w.write_str(&layout_lines(&table.layout(BorderWidths::default())).join("\n"))
+    }
+
+    fn write_titled_rule(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        title: impl Display,
+        _width: usize,
+    ) -> fmt::Result {
+        w.write_str(&width::strip_ansi(&title.to_string()))
+    }
+
+    fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> fmt::Result {
+        let guides = Guides::new("- ", "- ", "  ", "  ");
+        tree::write_tree(w, root, &label, &children, &guides)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_rows<R: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        // The rows don't have a header, so each one is a line of its own that can be written as
+        // soon as it arrives.  `widths` only limits the number of cells, since nothing is cut short
+        for row in rows {
+            let mut table = TableBuilder::new();
+            table
+                .row(row.into_iter().take(widths.len()))
+                .empty_behavior(EmptyBehavior::Minimal);
+            for line in layout_lines(&table.layout(BorderWidths::default())) {
+                writeln!(w, "{}", line)?;
+            }
+            w.flush()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::ACCESSIBLE;
+
+    #[test]
+    fn text_is_never_cut_to_a_width() {
+        let text = TableBuilder::new()
+            .header(["Name", "Description"])
+            .row(["borderrs", "Add stylish borders around your text"])
+            .max_width(16)
+            .build(&ACCESSIBLE);
+        assert_eq!(
+            text,
+            "Name: borderrs\nDescription: Add stylish borders around your text"
+        );
+    }
+
+    #[test]
+    fn empty_cells_are_left_out() {
+        assert_eq!(ACCESSIBLE.format_table(&[vec!["a", "", "c"]]), "a, c");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rows_are_streamed_as_lines() {
+        let mut out = Vec::new();
+        let rows = [["1", "", "one"], ["2", "two", ""]];
+        ACCESSIBLE.stream_rows(&mut out, rows, &[1, 2]).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "1\n2, two\n");
+    }
+}
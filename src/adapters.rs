@@ -0,0 +1,124 @@
+//! This module hosts the adapters returned by [`BorderFormatter::display`],
+//! [`BorderFormatter::slice`], [`BorderFormatter::table`], and
+//! [`BorderFormatter::as_table_field`].
+//!
+//! The adapters implement [`Display`] by writing straight into the [`fmt::Formatter`], so nothing
+//! is rendered until they are formatted and no intermediate [`String`] is needed.  Since they
+//! implement [`Display`] themselves, they can be nested inside of each other.
+//!
+//! The intended usage is as follows:
+//! ```rust
+//! use borderrs::{BorderFormatter, styles::{DOUBLE, THIN}};
+//! use std::io::Write;
+//!
+//! let rows = vec![vec![1, 2], vec![3, 4]];
+//! println!("{}", THIN.table(&rows));
+//!
+//! // Tables can be nested
+//! println!("{}", DOUBLE.display(&THIN.table(&rows)));
+//!
+//! // and streamed straight into an `io::Write`
+//! writeln!(std::io::stdout(), "{}", THIN.table(&rows)).unwrap();
+//! ```
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display, Write};
+
+use crate::BorderFormatter;
+
+/// Lazily adds a border around a value that implements [`Display`]
+///
+/// Created using [`BorderFormatter::display`].
+pub struct Bordered<'a, S: ?Sized, T: ?Sized> {
+    /// The style used to draw the border
+    style: &'a S,
+    /// The value to put inside of the border
+    value: &'a T,
+}
+
+impl<'a, S: ?Sized, T: ?Sized> Bordered<'a, S, T> {
+    pub(crate) fn new(style: &'a S, value: &'a T) -> Self {
+        Self { style, value }
+    }
+}
+
+impl<S: BorderFormatter + ?Sized, T: Display + ?Sized> Display for Bordered<'_, S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.style.write_display(f, self.value)
+    }
+}
+
+/// Lazily formats a slice as a horizontal table
+///
+/// Created using [`BorderFormatter::slice`].
+pub struct BorderedSlice<'a, S: ?Sized, T> {
+    /// The style used to draw the table
+    style: &'a S,
+    /// The entries in the table
+    slice: &'a [T],
+}
+
+impl<'a, S: ?Sized, T> BorderedSlice<'a, S, T> {
+    pub(crate) fn new(style: &'a S, slice: &'a [T]) -> Self {
+        Self { style, slice }
+    }
+}
+
+impl<S: BorderFormatter + ?Sized, T: Display> Display for BorderedSlice<'_, S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.style.write_slice(f, self.slice)
+    }
+}
+
+/// Lazily formats rows of cells as a 2D table
+///
+/// Created using [`BorderFormatter::table`].
+pub struct BorderedTable<'a, S: ?Sized, T> {
+    /// The style used to draw the table
+    style: &'a S,
+    /// The rows in the table
+    rows: &'a [Vec<T>],
+}
+
+impl<'a, S: ?Sized, T> BorderedTable<'a, S, T> {
+    pub(crate) fn new(style: &'a S, rows: &'a [Vec<T>]) -> Self {
+        Self { style, rows }
+    }
+}
+
+impl<S: BorderFormatter + ?Sized, T: Display> Display for BorderedTable<'_, S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.style.write_table(f, self.rows)
+    }
+}
+
+/// Lazily formats rows of cells as a 2D table for a field of a log message, starting on a line of
+/// its own
+///
+/// Both [`Display`] and [`Debug`] write the table, so it works with `log`'s `{}` and `{:?}` and
+/// with `tracing`'s `%` and `?` sigils.  Created using [`BorderFormatter::as_table_field`].
+pub struct TableField<'a, S: ?Sized, T> {
+    /// The style used to draw the table
+    style: &'a S,
+    /// The rows in the table
+    rows: &'a [Vec<T>],
+}
+
+impl<'a, S: ?Sized, T> TableField<'a, S, T> {
+    pub(crate) fn new(style: &'a S, rows: &'a [Vec<T>]) -> Self {
+        Self { style, rows }
+    }
+}
+
+impl<S: BorderFormatter + ?Sized, T: Display> Display for TableField<'_, S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The rest of the log line would push the first row out of line with the others
+        f.write_char('\n')?;
+        self.style.write_table(f, self.rows)
+    }
+}
+
+impl<S: BorderFormatter + ?Sized, T: Display> Debug for TableField<'_, S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,47 @@
+//! Date arithmetic for drawing month calendars, using the proleptic Gregorian calendar
+
+/// The name of each month, starting with January
+pub(crate) const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The abbreviated name of each day of the week, starting with Monday
+pub(crate) const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Whether `year` has a 29th of February
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Get the number of days in `month` (from 1 to 12) of `year`
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Get the day of the week of the first day of `month` (from 1 to 12) in `year`, where Monday is
+/// zero
+pub(crate) fn first_weekday(year: i32, month: u32) -> usize {
+    // Sakamoto's method, which counts from Sunday
+    const OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = i64::from(year) - i64::from(month < 3);
+    let leap_days = year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400);
+    let sunday = (year + leap_days + OFFSETS[month as usize - 1] + 1).rem_euclid(7);
+    // Shift the week so that it starts on Monday
+    ((sunday + 6) % 7) as usize
+}
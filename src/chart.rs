@@ -0,0 +1,113 @@
+//! Drawing numbers as bars and sparklines using block characters
+use alloc::{format, string::String, vec, vec::Vec};
+
+/// The character for a part of a bar that is filled in
+const FULL: char = '█';
+/// The character for a part of a bar that is at least half filled in
+const HALF: char = '▓';
+/// The character for a part of a bar that is empty
+const EMPTY: char = '░';
+
+/// The number of characters in each bar of a histogram
+pub(crate) const HISTOGRAM_WIDTH: usize = 20;
+
+/// The characters for each level of a sparkline, from lowest to highest
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Draw `value` as a bar that is `width` characters long, where a `value` of `max` fills the
+/// whole bar
+///
+/// Values outside of `0..=max` (and any value when `max` isn't positive) are clamped, so the bar
+/// is never longer than `width`.
+pub(crate) fn bar(value: f64, max: f64, width: usize) -> String {
+    let fraction = match max > 0.0 {
+        true => (value / max).clamp(0.0, 1.0),
+        false => 0.0,
+    };
+    // `NaN` fails every comparison, so it ends up as an empty bar
+    let filled = if fraction.is_nan() {
+        0.0
+    } else {
+        fraction * width as f64
+    };
+    let full = filled as usize;
+    let half = usize::from(full < width && filled - full as f64 >= 0.5);
+
+    let mut out = String::with_capacity(width * FULL.len_utf8());
+    out.extend(core::iter::repeat_n(FULL, full));
+    out.extend(core::iter::repeat_n(HALF, half));
+    out.extend(core::iter::repeat_n(EMPTY, width - full - half));
+    out
+}
+
+/// Draw `values` as a sparkline with a character for each value, where the smallest value is the
+/// lowest level and the largest value is the highest
+///
+/// If every value is the same they are all drawn at the lowest level, and values that aren't
+/// numbers are drawn as a space.
+pub(crate) fn sparkline(values: &[f64]) -> String {
+    let finite = || values.iter().copied().filter(|value| value.is_finite());
+    let min = finite().fold(f64::INFINITY, f64::min);
+    let max = finite().fold(f64::NEG_INFINITY, f64::max);
+    let top = (LEVELS.len() - 1) as f64;
+
+    values
+        .iter()
+        .map(|&value| match value.is_finite() {
+            // Round to the nearest level, without `f64::round` since it isn't in `core`
+            true if max > min => LEVELS[((value - min) / (max - min) * top + 0.5) as usize],
+            true => LEVELS[0],
+            false => ' ',
+        })
+        .collect()
+}
+
+/// Sort the finite `values` into `buckets` ranges of the same size that cover all of them,
+/// returning the label of each range and the number of values in it
+///
+/// Every range includes its start but not its end, except for the last one which includes both.
+/// If all of the values are the same, they go into a single range.
+pub(crate) fn histogram(values: &[f64], buckets: usize) -> Vec<(String, usize)> {
+    let values: Vec<_> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if values.is_empty() {
+        return Vec::new();
+    }
+
+    let buckets = if max > min { buckets.max(1) } else { 1 };
+    let step = (max - min) / buckets as f64;
+    let mut counts = vec![0; buckets];
+    for value in values {
+        // The maximum would start a bucket of its own, so it goes into the last one instead
+        let bucket = if step > 0.0 {
+            ((value - min) / step) as usize
+        } else {
+            0
+        };
+        counts[bucket.min(buckets - 1)] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let start = number(min + step * i as f64);
+            let end = number(if i + 1 == buckets {
+                max
+            } else {
+                min + step * (i + 1) as f64
+            });
+            let close = if i + 1 == buckets { ']' } else { ')' };
+            (format!("[{}, {}{}", start, end, close), count)
+        })
+        .collect()
+}
+
+/// Format `value` without any decimals if it is a whole number, or with two otherwise
+fn number(value: f64) -> String {
+    match value == value as i64 as f64 {
+        true => format!("{}", value as i64),
+        false => format!("{:.2}", value),
+    }
+}
@@ -0,0 +1,107 @@
+//! Colors that can be applied to the output using ANSI escape sequences
+//!
+//! For example,
+//! ```rust
+//! use borderrs::{color::Color, styles::THIN, BorderFormatter};
+//!
+//! let cyan = THIN.with_border_color(Color::Cyan);
+//! println!("{}", cyan.format_slice(&[1, 2, 3]));
+//! ```
+use core::fmt;
+
+/// A terminal color
+///
+/// The named colors use the terminal's palette, so how they actually look depends on the
+/// terminal's theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// A color from the 256-color palette
+    Fixed(u8),
+    /// A 24-bit color, given as red, green and blue
+    Rgb(u8, u8, u8),
+}
+
+/// The escape sequence that resets all colors and styles
+pub(crate) const RESET: &str = "\x1b[0m";
+
+impl Color {
+    /// Write the escape sequence that makes any following text this color
+    pub(crate) fn write_fg(self, w: &mut (impl fmt::Write + ?Sized)) -> fmt::Result {
+        self.write_sgr(w, 30, 90, 38)
+    }
+
+    /// Write the escape sequence that makes the background of any following text this color
+    pub(crate) fn write_bg(self, w: &mut (impl fmt::Write + ?Sized)) -> fmt::Result {
+        self.write_sgr(w, 40, 100, 48)
+    }
+
+    /// Write the SGR escape sequence for this color, where the named colors start at `base` (or
+    /// `bright` for the bright colors) and `extended` introduces a 256-color or 24-bit color
+    fn write_sgr(
+        self,
+        w: &mut (impl fmt::Write + ?Sized),
+        base: u8,
+        bright: u8,
+        extended: u8,
+    ) -> fmt::Result {
+        match self {
+            Color::Black => write!(w, "\x1b[{}m", base),
+            Color::Red => write!(w, "\x1b[{}m", base + 1),
+            Color::Green => write!(w, "\x1b[{}m", base + 2),
+            Color::Yellow => write!(w, "\x1b[{}m", base + 3),
+            Color::Blue => write!(w, "\x1b[{}m", base + 4),
+            Color::Magenta => write!(w, "\x1b[{}m", base + 5),
+            Color::Cyan => write!(w, "\x1b[{}m", base + 6),
+            Color::White => write!(w, "\x1b[{}m", base + 7),
+            Color::BrightBlack => write!(w, "\x1b[{}m", bright),
+            Color::BrightRed => write!(w, "\x1b[{}m", bright + 1),
+            Color::BrightGreen => write!(w, "\x1b[{}m", bright + 2),
+            Color::BrightYellow => write!(w, "\x1b[{}m", bright + 3),
+            Color::BrightBlue => write!(w, "\x1b[{}m", bright + 4),
+            Color::BrightMagenta => write!(w, "\x1b[{}m", bright + 5),
+            Color::BrightCyan => write!(w, "\x1b[{}m", bright + 6),
+            Color::BrightWhite => write!(w, "\x1b[{}m", bright + 7),
+            Color::Fixed(n) => write!(w, "\x1b[{};5;{}m", extended, n),
+            Color::Rgb(r, g, b) => write!(w, "\x1b[{};2;{};{};{}m", extended, r, g, b),
+        }
+    }
+}
+
+/// Write the text written by `write` into `w` in `color` on top of `background`, resetting the
+/// colors afterwards
+pub(crate) fn write_painted<W: fmt::Write + ?Sized>(
+    w: &mut W,
+    color: Option<Color>,
+    background: Option<Color>,
+    write: impl FnOnce(&mut W) -> fmt::Result,
+) -> fmt::Result {
+    if color.is_none() && background.is_none() {
+        return write(w);
+    }
+    if let Some(color) = color {
+        color.write_fg(w)?;
+    }
+    if let Some(background) = background {
+        background.write_bg(w)?;
+    }
+    write(w)?;
+    w.write_str(RESET)
+}
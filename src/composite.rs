@@ -0,0 +1,210 @@
+//! This module hosts [`CompositeBorderStyle`], which draws the outer frame of a table in one
+//! style and the grid inside of it in another.
+//!
+//! The intended usage is as follows:
+//! ```rust
+//! use borderrs::{composite::CompositeBorderStyle, styles, BorderFormatter};
+//!
+//! const STYLE: CompositeBorderStyle = CompositeBorderStyle::new(styles::DOUBLE, styles::ASCII);
+//! println!("{}", STYLE.format_table(&[vec![1, 2], vec![3, 4]]));
+//! ```
+use alloc::string::String;
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::{collections::HashMap, io};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    color::Color,
+    error::BorderError,
+    frame::{Frame, Piece},
+    junction::{self, Weight, DOWN, LEFT, RIGHT, UP},
+    table::{BorderWidths, TableBuilder},
+    tree, Alignment, BorderFormatter, SimpleBorderStyle,
+};
+
+/// A border style that draws the outer frame of a table using one style and the lines between
+/// its cells using another
+///
+/// The edges and corners come from `outer`, and the lines and crosses inside of the table come
+/// from `inner`.  Where an inner line meets the outer frame, the junction is worked out from the
+/// weight of the two lines, so a double frame around thin lines gets junctions like `╤` and `╟`.
+/// If there isn't a box-drawing character for that mix of lines (or the frame isn't made of
+/// box-drawing characters, like [`ASCII`](crate::styles::ASCII)), the junction from `outer` is
+/// used instead.
+///
+/// The border is drawn in the color of `outer`, if it has one.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{composite::CompositeBorderStyle, styles, BorderFormatter};
+/// let style = CompositeBorderStyle::new(styles::DOUBLE, styles::ASCII);
+/// let rows = vec![vec!["Name", "Age"], vec!["Jon", "38"], vec!["Jake", "25"]];
+///
+/// println!("{}", style.format_table(&rows));
+///
+/// // This is how `DOUBLE_OUTER_THIN_INNER` is drawn
+/// assert_eq!(
+///     CompositeBorderStyle::new(styles::DOUBLE, styles::THIN).format_table(&rows),
+///     styles::DOUBLE_OUTER_THIN_INNER.format_table(&rows),
+/// );
+/// ```
+/// produces the output
+/// ```text
+/// ╔════╤═══╗
+/// ║Name|Age║
+/// ╟----+---╢
+/// ║ Jon| 38║
+/// ╟----+---╢
+/// ║Jake| 25║
+/// ╚════╧═══╝
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeBorderStyle {
+    /// The style of the edges and corners of the table
+    outer: SimpleBorderStyle,
+    /// The style of the lines between the cells of the table
+    inner: SimpleBorderStyle,
+}
+
+impl CompositeBorderStyle {
+    /// Create a style that draws the frame of a table using `outer` and the lines between its
+    /// cells using `inner`
+    pub const fn new(outer: SimpleBorderStyle, inner: SimpleBorderStyle) -> Self {
+        Self { outer, inner }
+    }
+
+    /// Get the style of the edges and corners of the table
+    pub const fn outer(&self) -> &SimpleBorderStyle {
+        &self.outer
+    }
+
+    /// Get the style of the lines between the cells of the table
+    pub const fn inner(&self) -> &SimpleBorderStyle {
+        &self.inner
+    }
+
+    /// Get the character used for `piece`
+    fn piece(&self, piece: Piece) -> char {
+        let outer_vertical = self.outer.piece(Piece::Vertical);
+        let outer_horizontal = self.outer.piece(Piece::Horizontal);
+        let inner_vertical = self.inner.piece(Piece::InnerVertical);
+        let inner_horizontal = self.inner.piece(Piece::InnerHorizontal);
+        let (edge, arm, line) = match piece {
+            Piece::HorizontalDown => (outer_horizontal, DOWN, inner_vertical),
+            Piece::HorizontalUp => (outer_horizontal, UP, inner_vertical),
+            Piece::VerticalRight => (outer_vertical, RIGHT, inner_horizontal),
+            Piece::VerticalLeft => (outer_vertical, LEFT, inner_horizontal),
+            Piece::InnerVertical
+            | Piece::InnerHorizontal
+            | Piece::HeaderHorizontal
+            | Piece::Cross => return self.inner.piece(piece),
+            _ => return self.outer.piece(piece),
+        };
+
+        // Add the inner line to the outer edge, as long as the edge is a box-drawing line
+        let resolved = junction::arms(edge).and_then(|mut arms| {
+            let from = (arm + 2) % 4;
+            arms[arm] = junction::weight(line, from);
+            match arms[arm] {
+                Weight::Empty => Some(edge),
+                _ => junction::junction(arms),
+            }
+        });
+        resolved.unwrap_or_else(|| self.outer.piece(piece))
+    }
+}
+
+impl Frame for CompositeBorderStyle {
+    fn write_piece(&self, w: &mut (impl fmt::Write + ?Sized), piece: Piece) -> fmt::Result {
+        w.write_char(self.piece(piece))
+    }
+
+    fn piece_width(&self, piece: Piece) -> usize {
+        self.piece(piece).width().unwrap_or(0)
+    }
+
+    fn border_color(&self) -> Option<Color> {
+        self.outer.border_color
+    }
+}
+
+impl BorderFormatter for CompositeBorderStyle {
+    fn validate(&self) -> Result<(), BorderError> {
+        self.check_pieces()
+    }
+
+    fn border_widths(&self) -> BorderWidths {
+        self.frame_widths()
+    }
+
+    fn format_slice(&self, slice: &[impl Display]) -> String {
+        self.render_slice(slice, Alignment::Right)
+    }
+
+    fn format_slice_aligned(&self, slice: &[impl Display], alignment: Alignment) -> String {
+        self.render_slice(slice, alignment)
+    }
+
+    fn write_slice_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        alignment: Alignment,
+    ) -> fmt::Result {
+        self.draw_slice(w, slice, alignment)
+    }
+
+    fn write_table_builder(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        table: &TableBuilder,
+    ) -> fmt::Result {
+        self.draw_table(w, table)
+    }
+
+    fn write_titled_rule(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        title: impl Display,
+        width: usize,
+    ) -> fmt::Result {
+        self.draw_rule(w, title, width)
+    }
+
+    fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> fmt::Result {
+        tree::write_tree(w, root, &label, &children, &self.tree_guides())
+    }
+
+    #[cfg(feature = "std")]
+    fn format_hash_map_headers(
+        &self,
+        map: &HashMap<impl Display, impl Display>,
+        key_header: impl AsRef<str>,
+        value_header: impl AsRef<str>,
+    ) -> String {
+        self.render_table(
+            TableBuilder::new()
+                .header([key_header.as_ref(), value_header.as_ref()])
+                .pairs(map),
+        )
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_rows<R: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        self.draw_stream(w, rows, widths)
+    }
+}
@@ -0,0 +1,80 @@
+//! Building [`TableBuilder`]s from [`csv`] records
+//!
+//! Requires the `csv` feature.
+use std::io;
+
+use csv::{Reader, StringRecord};
+
+use crate::table::TableBuilder;
+
+/// Build a table with a row for each record, without a header
+///
+/// Since [`Result`] can be collected from an iterator of results, the records read from a
+/// [`Reader`] can be collected straight into a table, stopping at the first error.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, table::TableBuilder};
+/// let mut reader = csv::ReaderBuilder::new()
+///     .has_headers(false)
+///     .from_reader("Jon,38\nJake,25\n".as_bytes());
+///
+/// let table: TableBuilder = reader.records().collect::<Result<_, _>>()?;
+/// println!("{}", table.build(&styles::THIN));
+/// # Ok::<_, csv::Error>(())
+/// ```
+/// produces the output
+/// ```text
+/// ┌────┬──┐
+/// │ Jon│38│
+/// ├────┼──┤
+/// │Jake│25│
+/// └────┴──┘
+/// ```
+impl FromIterator<StringRecord> for TableBuilder {
+    fn from_iter<I: IntoIterator<Item = StringRecord>>(records: I) -> Self {
+        let mut table = TableBuilder::new();
+        for record in records {
+            table.row(&record);
+        }
+        table
+    }
+}
+
+/// Read every record from `reader` into a table, using its headers as the header of the table
+/// if it has them
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, table::TableBuilder};
+/// let mut reader = csv::Reader::from_reader("Name,Age\nJon,38\nJake,25\n".as_bytes());
+///
+/// let table = TableBuilder::try_from(&mut reader)?;
+/// println!("{}", table.build(&styles::THIN));
+/// # Ok::<_, csv::Error>(())
+/// ```
+/// produces the output
+/// ```text
+/// ┌────┬───┐
+/// │Name│Age│
+/// ├────┼───┤
+/// │ Jon│ 38│
+/// ├────┼───┤
+/// │Jake│ 25│
+/// └────┴───┘
+/// ```
+impl<R: io::Read> TryFrom<&mut Reader<R>> for TableBuilder {
+    type Error = csv::Error;
+
+    fn try_from(reader: &mut Reader<R>) -> Result<Self, Self::Error> {
+        let headers = reader
+            .has_headers()
+            .then(|| reader.headers().cloned())
+            .transpose()?;
+        let mut table: TableBuilder = reader.records().collect::<Result<_, _>>()?;
+        if let Some(headers) = headers {
+            table.header(&headers);
+        }
+        Ok(table)
+    }
+}
@@ -0,0 +1,72 @@
+//! Comparing two lists of lines to find what changed between them
+use alloc::{vec, vec::Vec};
+
+/// How a line changed between the left and right sides of a diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Change<'a> {
+    /// The line is the same on both sides
+    Same(&'a str),
+    /// The line is only on the left
+    Removed(&'a str),
+    /// The line is only on the right
+    Added(&'a str),
+    /// The line on the left was replaced by the line on the right
+    Changed(&'a str, &'a str),
+}
+
+/// Find the changes that turn `left` into `right`, keeping as many lines the same as possible
+///
+/// Lines that are removed right before lines that are added are paired up as changed lines, so
+/// the two sides line up like they would when shown next to each other.
+pub(crate) fn diff<'a>(left: &[&'a str], right: &[&'a str]) -> Vec<Change<'a>> {
+    // The length of the longest common subsequence of `left[i..]` and `right[j..]`
+    let mut lengths = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lengths[i][j] = match left[i] == right[j] {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    let mut changes = Vec::new();
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            pair_up(&mut changes, &mut removed, &mut added);
+            changes.push(Change::Same(left[i]));
+            i += 1;
+            j += 1;
+        } else if j < right.len() && (i == left.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+            added.push(right[j]);
+            j += 1;
+        } else {
+            removed.push(left[i]);
+            i += 1;
+        }
+    }
+    pair_up(&mut changes, &mut removed, &mut added);
+    changes
+}
+
+/// Move a run of `removed` and `added` lines into `changes`, pairing them up as changed lines
+/// for as long as both have lines left
+fn pair_up<'a>(
+    changes: &mut Vec<Change<'a>>,
+    removed: &mut Vec<&'a str>,
+    added: &mut Vec<&'a str>,
+) {
+    let paired = removed.len().min(added.len());
+    changes.extend(
+        removed
+            .iter()
+            .zip(added.iter())
+            .map(|(&left, &right)| Change::Changed(left, right)),
+    );
+    changes.extend(removed[paired..].iter().map(|&left| Change::Removed(left)));
+    changes.extend(added[paired..].iter().map(|&right| Change::Added(right)));
+    removed.clear();
+    added.clear();
+}
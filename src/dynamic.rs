@@ -0,0 +1,246 @@
+//! This module hosts the [`DynBorderFormatter`] trait, which allows a style to be picked at
+//! runtime and used as a trait object.
+//!
+//! [`BorderFormatter`] can't be made into a trait object because most of its methods are
+//! generic, so every style also implements [`DynBorderFormatter`], which only works with text
+//! that has already been formatted.  In turn, `dyn DynBorderFormatter` implements
+//! [`BorderFormatter`], so all of the usual `format_*` methods can be called on it.
+//!
+//! The intended usage is as follows:
+//! ```rust
+//! use borderrs::{dynamic::DynBorderFormatter, styles, BorderFormatter};
+//!
+//! struct Config {
+//!     style: Box<dyn DynBorderFormatter>,
+//! }
+//!
+//! let fancy = true;
+//! let config = Config {
+//!     style: if fancy {
+//!         Box::new(styles::ROUNDED)
+//!     } else {
+//!         Box::new(styles::MARKDOWN)
+//!     },
+//! };
+//! println!("{}", config.style.format_table(&[vec![1, 2], vec![3, 4]]));
+//! ```
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{
+    error::BorderError,
+    table::{BorderWidths, TableBuilder},
+    Alignment, BorderFormatter,
+};
+
+/// The object-safe core of [`BorderFormatter`], which works with text that has already been
+/// formatted
+///
+/// This is implemented for every [`BorderFormatter`], so it never needs to be implemented by
+/// hand.  See [the module docs](self) for how to use it.
+pub trait DynBorderFormatter {
+    /// Write a slice into `w` as an horizontal table, aligning each entry using `alignment`
+    ///
+    /// See [`BorderFormatter::write_slice_aligned`] for more info
+    fn write_slice_dyn(
+        &self,
+        w: &mut dyn fmt::Write,
+        slice: &[String],
+        alignment: Alignment,
+    ) -> fmt::Result;
+
+    /// Write a table that was built using a [`TableBuilder`] into `w`
+    ///
+    /// See [`BorderFormatter::write_table_builder`] for more info
+    fn write_table_dyn(&self, w: &mut dyn fmt::Write, table: &TableBuilder) -> fmt::Result;
+
+    /// Write a horizontal line that is `width` columns wide into `w`, with `title` in the middle
+    /// of it
+    ///
+    /// See [`BorderFormatter::write_titled_rule`] for more info
+    fn write_titled_rule_dyn(
+        &self,
+        w: &mut dyn fmt::Write,
+        title: &str,
+        width: usize,
+    ) -> fmt::Result;
+
+    /// Write a tree into `w`, where `labels` is the text of each node and `children` is the
+    /// indices of the nodes below each node, starting from the root at index `0`
+    ///
+    /// See [`BorderFormatter::write_tree_with`] for more info
+    fn write_tree_dyn(
+        &self,
+        w: &mut dyn fmt::Write,
+        labels: &[String],
+        children: &[Vec<usize>],
+    ) -> fmt::Result;
+
+    /// Write each row into `w` as soon as it is produced by `rows`, with every column exactly as
+    /// wide as the matching entry of `widths`
+    ///
+    /// See [`BorderFormatter::stream_rows`] for more info
+    #[cfg(feature = "std")]
+    fn stream_rows_dyn(
+        &self,
+        w: &mut dyn io::Write,
+        rows: &mut dyn Iterator<Item = Vec<String>>,
+        widths: &[usize],
+    ) -> io::Result<()>;
+
+    /// Check that the style can draw tables without breaking them
+    ///
+    /// See [`BorderFormatter::validate`] for more info
+    fn validate_dyn(&self) -> Result<(), BorderError>;
+
+    /// Get how many columns the vertical borders of the tables drawn in this style take up
+    ///
+    /// See [`BorderFormatter::border_widths`] for more info
+    fn border_widths_dyn(&self) -> BorderWidths;
+}
+
+impl<S: BorderFormatter> DynBorderFormatter for S {
+    fn write_slice_dyn(
+        &self,
+        w: &mut dyn fmt::Write,
+        slice: &[String],
+        alignment: Alignment,
+    ) -> fmt::Result {
+        self.write_slice_aligned(w, slice, alignment)
+    }
+
+    fn write_table_dyn(&self, w: &mut dyn fmt::Write, table: &TableBuilder) -> fmt::Result {
+        self.write_table_builder(w, table)
+    }
+
+    fn write_titled_rule_dyn(
+        &self,
+        w: &mut dyn fmt::Write,
+        title: &str,
+        width: usize,
+    ) -> fmt::Result {
+        self.write_titled_rule(w, title, width)
+    }
+
+    fn write_tree_dyn(
+        &self,
+        w: &mut dyn fmt::Write,
+        labels: &[String],
+        children: &[Vec<usize>],
+    ) -> fmt::Result {
+        let ids: Vec<usize> = (0..labels.len()).collect();
+        let Some(root) = ids.first() else {
+            return Ok(());
+        };
+        self.write_tree_with(
+            w,
+            root,
+            |&node| &labels[node],
+            |&node| children[node].iter().map(|&child| &ids[child]),
+        )
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_rows_dyn(
+        &self,
+        w: &mut dyn io::Write,
+        rows: &mut dyn Iterator<Item = Vec<String>>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        self.stream_rows(w, rows, widths)
+    }
+
+    fn validate_dyn(&self) -> Result<(), BorderError> {
+        self.validate()
+    }
+
+    fn border_widths_dyn(&self) -> BorderWidths {
+        self.border_widths()
+    }
+}
+
+/// Add `node` and everything below it to `labels` and `nodes`, returning the index of `node`
+fn flatten<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+    node: &'a T,
+    label: &impl Fn(&'a T) -> L,
+    children: &impl Fn(&'a T) -> C,
+    labels: &mut Vec<String>,
+    nodes: &mut Vec<Vec<usize>>,
+) -> usize {
+    let id = labels.len();
+    labels.push(label(node).to_string());
+    nodes.push(Vec::new());
+    for child in children(node) {
+        let child = flatten(child, label, children, labels, nodes);
+        nodes[id].push(child);
+    }
+    id
+}
+
+impl BorderFormatter for dyn DynBorderFormatter + '_ {
+    fn write_slice_aligned(
+        &self,
+        mut w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        alignment: Alignment,
+    ) -> fmt::Result {
+        let slice: Vec<_> = slice.iter().map(ToString::to_string).collect();
+        self.write_slice_dyn(&mut w, &slice, alignment)
+    }
+
+    fn write_table_builder(
+        &self,
+        mut w: &mut (impl fmt::Write + ?Sized),
+        table: &TableBuilder,
+    ) -> fmt::Result {
+        self.write_table_dyn(&mut w, table)
+    }
+
+    fn write_titled_rule(
+        &self,
+        mut w: &mut (impl fmt::Write + ?Sized),
+        title: impl Display,
+        width: usize,
+    ) -> fmt::Result {
+        self.write_titled_rule_dyn(&mut w, &title.to_string(), width)
+    }
+
+    fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        mut w: &mut (impl fmt::Write + ?Sized),
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> fmt::Result {
+        let mut labels = Vec::new();
+        let mut nodes = Vec::new();
+        flatten(root, &label, &children, &mut labels, &mut nodes);
+        self.write_tree_dyn(&mut w, &labels, &nodes)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_rows<R: IntoIterator<Item = impl Display>>(
+        &self,
+        mut w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        let mut rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|cell| cell.to_string()).collect());
+        self.stream_rows_dyn(&mut w, &mut rows, widths)
+    }
+
+    fn validate(&self) -> Result<(), BorderError> {
+        self.validate_dyn()
+    }
+
+    fn border_widths(&self) -> BorderWidths {
+        self.border_widths_dyn()
+    }
+}
@@ -0,0 +1,76 @@
+//! This module hosts [`BorderError`], which is returned by the `try_*` methods, such as
+//! [`BorderFormatter::try_format_table`] and [`TableBuilder::try_build`], instead of silently
+//! producing broken output.
+//!
+//! ```rust
+//! use borderrs::{error::BorderError, styles, BorderFormatter};
+//!
+//! let result = styles::THIN.try_format_slice(&["one", "two\0"]);
+//! assert_eq!(result, Err(BorderError::ControlCharacter('\0')));
+//! ```
+//!
+//! [`BorderFormatter::try_format_table`]: crate::BorderFormatter::try_format_table
+//! [`TableBuilder::try_build`]: crate::table::TableBuilder::try_build
+use core::fmt::{self, Display};
+
+use crate::width::{self, Segment};
+
+/// An error that stops a table from being drawn correctly
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BorderError {
+    /// The table can't fit within the width it was given, even with every column shrunk to a
+    /// single column of text
+    TooNarrow {
+        /// The width that the table was given
+        width: usize,
+        /// The narrowest that the table can be
+        needed: usize,
+    },
+    /// The pieces of the style don't line up with each other, or contain control characters
+    InvalidStyle(&'static str),
+    /// The text contains a control character (other than a line break or an ANSI escape
+    /// sequence), which would move the cursor and break the borders
+    ControlCharacter(char),
+    /// The output couldn't be written
+    Fmt(fmt::Error),
+}
+
+impl Display for BorderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooNarrow { width, needed } => write!(
+                f,
+                "the table needs at least {} columns but only has {}",
+                needed, width
+            ),
+            Self::InvalidStyle(reason) => write!(f, "invalid border style: {}", reason),
+            Self::ControlCharacter(c) => {
+                write!(f, "the text contains the control character {:?}", c)
+            }
+            Self::Fmt(_) => f.write_str("an error occurred while writing the table"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BorderError {}
+
+impl From<fmt::Error> for BorderError {
+    fn from(error: fmt::Error) -> Self {
+        Self::Fmt(error)
+    }
+}
+
+/// Make sure that `text` doesn't contain any control characters, other than line breaks and the
+/// ones inside of ANSI escape sequences
+pub(crate) fn check_text(text: &str) -> Result<(), BorderError> {
+    let control = width::segments(text).find_map(|segment| match segment {
+        Segment::Text(text) => text.chars().find(|&c| c.is_control() && c != '\n'),
+        Segment::Escape(_) => None,
+    });
+    match control {
+        Some(c) => Err(BorderError::ControlCharacter(c)),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,1000 @@
+//! Drawing tables with box-drawing borders, shared by every style that draws a frame of lines
+//! around its cells
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display},
+    iter,
+};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{
+    color::{self, Color},
+    error::BorderError,
+    table::{BorderWidths, Layout, LayoutCell, TableBuilder},
+    tree::Guides,
+    width, write_spaces, Alignment,
+};
+
+/// One of the pieces that a frame is drawn with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Piece {
+    /// The vertical line on the left and right edges
+    Vertical,
+    /// The horizontal line on the top and bottom edges
+    Horizontal,
+    /// The vertical separator between columns
+    InnerVertical,
+    /// The horizontal separator between rows
+    InnerHorizontal,
+    /// The horizontal separator between the header and the first row
+    HeaderHorizontal,
+    /// The junction connecting up, left, and right
+    HorizontalUp,
+    /// The junction connecting down, left, and right
+    HorizontalDown,
+    /// The junction connecting up, down, and right
+    VerticalRight,
+    /// The junction connecting up, down, and left
+    VerticalLeft,
+    /// The top-left corner
+    TopLeft,
+    /// The top-right corner
+    TopRight,
+    /// The bottom-left corner
+    BottomLeft,
+    /// The bottom-right corner
+    BottomRight,
+    /// The junction connecting in every direction
+    Cross,
+}
+
+/// A style that draws a frame of lines around its cells
+///
+/// Implementors only need to be able to write each [`Piece`] and say how wide it is, and the
+/// default methods take care of drawing the table.
+pub(crate) trait Frame {
+    /// Write `piece` into `w`
+    fn write_piece(&self, w: &mut (impl fmt::Write + ?Sized), piece: Piece) -> fmt::Result;
+
+    /// Get the number of columns that `piece` takes up
+    fn piece_width(&self, piece: Piece) -> usize;
+
+    /// Get the color that the border is drawn in, if it has one
+    fn border_color(&self) -> Option<Color>;
+
+    /// Make sure that the pieces don't contain any control characters, and that the corners and
+    /// junctions are as wide as the vertical lines they connect to so that the columns line up
+    fn check_pieces(&self) -> Result<(), BorderError> {
+        const PIECES: [Piece; 14] = [
+            Piece::Vertical,
+            Piece::Horizontal,
+            Piece::InnerVertical,
+            Piece::InnerHorizontal,
+            Piece::HeaderHorizontal,
+            Piece::HorizontalUp,
+            Piece::HorizontalDown,
+            Piece::VerticalRight,
+            Piece::VerticalLeft,
+            Piece::TopLeft,
+            Piece::TopRight,
+            Piece::BottomLeft,
+            Piece::BottomRight,
+            Piece::Cross,
+        ];
+        for piece in PIECES {
+            let mut text = String::new();
+            self.write_piece(&mut text, piece)?;
+            if text.chars().any(char::is_control) {
+                return Err(BorderError::InvalidStyle(
+                    "the pieces can't contain control characters",
+                ));
+            }
+        }
+
+        let edges = [
+            (
+                Piece::Vertical,
+                [Piece::TopLeft, Piece::VerticalRight, Piece::BottomLeft],
+            ),
+            (
+                Piece::Vertical,
+                [Piece::TopRight, Piece::VerticalLeft, Piece::BottomRight],
+            ),
+            (
+                Piece::InnerVertical,
+                [Piece::HorizontalDown, Piece::Cross, Piece::HorizontalUp],
+            ),
+        ];
+        for (vertical, joins) in edges {
+            let width = self.piece_width(vertical);
+            if joins.iter().any(|&join| self.piece_width(join) != width) {
+                return Err(BorderError::InvalidStyle(
+                    "the corners and junctions must be as wide as the vertical lines they connect to",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get how many columns the vertical borders take up, for laying out tables drawn with them
+    fn frame_widths(&self) -> BorderWidths {
+        BorderWidths {
+            edges: self.piece_width(Piece::Vertical) * 2,
+            inner: self.piece_width(Piece::InnerVertical),
+        }
+    }
+
+    /// Write the border drawn by `write` into `w` in the border's color
+    fn write_colored<W: fmt::Write + ?Sized>(
+        &self,
+        w: &mut W,
+        write: impl FnOnce(&mut W) -> fmt::Result,
+    ) -> fmt::Result {
+        match self.border_color() {
+            Some(color) => {
+                color.write_fg(w)?;
+                write(w)?;
+                w.write_str(color::RESET)
+            }
+            None => write(w),
+        }
+    }
+
+    /// Write a single `piece` into `w` in the border's color
+    fn write_border(&self, w: &mut (impl fmt::Write + ?Sized), piece: Piece) -> fmt::Result {
+        self.write_colored(w, |w| self.write_piece(w, piece))
+    }
+
+    /// Fill `width` columns with `piece`, repeating it as many times as it fits and filling any
+    /// columns that are left over with spaces
+    fn write_fill(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        piece: Piece,
+        width: usize,
+    ) -> fmt::Result {
+        let piece_width = self.piece_width(piece);
+        let repeat = width.checked_div(piece_width).unwrap_or(0);
+        for _ in 0..repeat {
+            self.write_piece(w, piece)?;
+        }
+        for _ in repeat * piece_width..width {
+            w.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    /// Write `line` with a column for each of the `widths`
+    fn write_line(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        line: &Line,
+        widths: &[usize],
+    ) -> fmt::Result {
+        let inner = self.piece_width(Piece::InnerVertical);
+        self.write_colored(w, |w| {
+            if let Some(left) = line.left {
+                self.write_piece(w, left)?;
+            }
+            let mut offset = 0;
+            for (i, &width) in widths.iter().enumerate() {
+                if i > 0 {
+                    match line.joins.get(i - 1).copied().unwrap_or(Join::Through) {
+                        Join::Piece(piece) => self.write_piece(w, piece)?,
+                        Join::Through => self.write_run(w, line, offset, inner)?,
+                        Join::Blank => write_spaces(w, inner)?,
+                    }
+                    offset += inner;
+                }
+                match line.ruled.get(i).copied().unwrap_or(true) {
+                    true => self.write_run(w, line, offset, width)?,
+                    false => write_spaces(w, width)?,
+                }
+                offset += width;
+            }
+            if let Some(right) = line.right {
+                self.write_piece(w, right)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Write `width` columns of the horizontal part of `line`, starting `offset` columns after
+    /// its left end, including any of its junctions that fall within them
+    fn write_run(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        line: &Line,
+        offset: usize,
+        width: usize,
+    ) -> fmt::Result {
+        let mut at = offset;
+        let run = offset..offset + width;
+        for &(position, piece) in line.junctions.iter().filter(|(p, _)| run.contains(p)) {
+            self.write_fill(w, line.horizontal, position.saturating_sub(at))?;
+            self.write_piece(w, piece)?;
+            at = position + self.piece_width(piece);
+        }
+        self.write_fill(w, line.horizontal, run.end.saturating_sub(at))
+    }
+
+    /// Write the top line for a table with the given column widths, where `below` is whether
+    /// each column of the first row is separated from the next one and `junctions` are where the
+    /// lines of tables nested in the first row meet it
+    fn write_top_line(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        widths: &[usize],
+        below: &[bool],
+        junctions: Vec<(usize, Piece)>,
+    ) -> fmt::Result {
+        let line = Line::new(
+            Piece::Horizontal,
+            Some((Piece::TopLeft, Piece::TopRight)),
+            (&[], below),
+            &[],
+        );
+        self.write_line(w, &Line { junctions, ..line }, widths)
+    }
+
+    /// Write the top line for a table with the given column widths, with `title` inset into it
+    /// near the left corner, in the middle, or near the right corner depending on `alignment`
+    ///
+    /// The title replaces the pieces of the line that it covers, so `widths` must leave enough
+    /// room for it.
+    fn write_titled_top_line(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        widths: &[usize],
+        below: &[bool],
+        junctions: Vec<(usize, Piece)>,
+        (title, alignment): (&str, Alignment),
+    ) -> fmt::Result {
+        let mut line = String::new();
+        self.write_top_line(&mut line, widths, below, junctions)?;
+
+        let title = format!(" {} ", title);
+        let title_width = width::display_width(&title);
+        // Leave at least one piece of the line between the title and either corner
+        let left = self.piece_width(Piece::TopLeft);
+        let inner = width::display_width(&line) - left - self.piece_width(Piece::TopRight);
+        let offset = match alignment {
+            Alignment::Left => 1,
+            Alignment::Right => inner.saturating_sub(title_width + 1),
+            Alignment::Center => inner.saturating_sub(title_width) / 2,
+        };
+        let (start, rest) = width::split_at_width(&line, left + offset.max(1));
+        let (covered, mut end) = width::split_at_width(rest, title_width);
+
+        // A wide piece can stick out past the end of the title, so it's replaced with spaces
+        let mut covered_width = width::display_width(covered);
+        while covered_width < title_width && !end.is_empty() {
+            let (piece, rest) = width::split_at_width(end, 1);
+            covered_width += width::display_width(piece);
+            end = rest;
+        }
+
+        w.write_str(start)?;
+        w.write_str(&title)?;
+        for _ in title_width..covered_width {
+            w.write_char(' ')?;
+        }
+        w.write_str(end)
+    }
+
+    /// Write the bottom line for a table with the given column widths, where `above` is whether
+    /// each column of the last row is separated from the next one and `junctions` are where the
+    /// lines of tables nested in the last row meet it
+    fn write_bottom_line(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        widths: &[usize],
+        above: &[bool],
+        junctions: Vec<(usize, Piece)>,
+    ) -> fmt::Result {
+        let line = Line::new(
+            Piece::Horizontal,
+            Some((Piece::BottomLeft, Piece::BottomRight)),
+            (above, &[]),
+            &[],
+        );
+        self.write_line(w, &Line { junctions, ..line }, widths)
+    }
+
+    /// Draw a horizontal line `width` columns wide with `title` in the middle of it, using the
+    /// same piece as the top and bottom lines
+    fn draw_rule(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        title: impl Display,
+        width: usize,
+    ) -> fmt::Result {
+        let (left, title, right) = crate::rule_parts(title, width);
+        self.write_colored(w, |w| self.write_fill(w, Piece::Horizontal, left))?;
+        w.write_str(&title)?;
+        self.write_colored(w, |w| self.write_fill(w, Piece::Horizontal, right))
+    }
+
+    /// Get the guides for drawing a tree with the pieces of this frame
+    fn tree_guides(&self) -> Guides {
+        // Each guide is a junction followed by a short horizontal line and a space, and the
+        // guides under it line up with the junction
+        let guide = |junction: Piece| {
+            crate::write_to_string(|out| {
+                self.write_colored(out, |out| {
+                    self.write_piece(out, junction)?;
+                    self.write_fill(out, Piece::Horizontal, 2)
+                })?;
+                out.push(' ');
+                Ok(())
+            })
+        };
+        let width = self.piece_width(Piece::VerticalRight) + 3;
+        let pipe = crate::write_to_string(|out| {
+            self.write_border(out, Piece::Vertical)?;
+            write_spaces(out, width.saturating_sub(self.piece_width(Piece::Vertical)))
+        });
+        Guides {
+            branch: guide(Piece::VerticalRight),
+            last: guide(Piece::BottomLeft),
+            pipe,
+            blank: " ".repeat(width),
+        }
+    }
+
+    /// Draw a slice as an horizontal table, aligning each entry using `alignment`
+    fn draw_slice(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        alignment: Alignment,
+    ) -> fmt::Result {
+        let entries: Vec<_> = slice.iter().map(ToString::to_string).collect();
+        let entries = Entries::new(&entries);
+        self.draw_entries(w, &entries, alignment)
+    }
+
+    /// Draw a slice as an horizontal table into a new [`String`], which has room for the whole
+    /// table reserved up front
+    fn render_slice(&self, slice: &[impl Display], alignment: Alignment) -> String {
+        let entries: Vec<_> = slice.iter().map(ToString::to_string).collect();
+        let entries = Entries::new(&entries);
+
+        let cols = entries.lines.len();
+        let border = self.border_len();
+        let text: usize = entries.lines.iter().flatten().map(|line| line.len()).sum();
+        // The top and bottom lines, then a border on either side of each column of every line,
+        // the padding around each entry, and a newline at the end of each line
+        let capacity = 2 * (cols * entries.width + cols + 1) * border
+            + entries.height * ((cols + 1) * border + cols * entries.width + 1)
+            + text
+            + 1;
+
+        let mut out = String::with_capacity(capacity);
+        self.draw_entries(&mut out, &entries, alignment)
+            .expect("writing to a String can't fail");
+        out
+    }
+
+    /// Draw entries that have already been split into lines and measured, aligning each entry
+    /// using `alignment`
+    fn draw_entries(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        entries: &Entries,
+        alignment: Alignment,
+    ) -> fmt::Result {
+        // Let the table decide what an empty slice looks like
+        if entries.lines.is_empty() {
+            return self.draw_table(w, &TableBuilder::new());
+        }
+        // Every column has the same width
+        let widths = vec![entries.width; entries.lines.len()];
+        let separated = vec![true; entries.lines.len().saturating_sub(1)];
+
+        self.write_top_line(w, &widths, &separated, Vec::new())?;
+        w.write_char('\n')?;
+        for i in 0..entries.height {
+            self.write_border(w, Piece::Vertical)?;
+            for (col, lines) in entries.lines.iter().enumerate() {
+                if col > 0 {
+                    self.write_border(w, Piece::InnerVertical)?;
+                }
+                let line = lines.get(i).copied().unwrap_or("");
+                alignment.write_aligned(w, line, entries.width)?;
+            }
+            self.write_border(w, Piece::Vertical)?;
+            w.write_char('\n')?;
+        }
+        self.write_bottom_line(w, &widths, &separated, Vec::new())
+    }
+
+    /// Get the largest number of bytes that a single piece of the border takes up, including
+    /// any escape sequences for its color
+    fn border_len(&self) -> usize {
+        [
+            Piece::Vertical,
+            Piece::Horizontal,
+            Piece::InnerVertical,
+            Piece::InnerHorizontal,
+            Piece::HeaderHorizontal,
+            Piece::Cross,
+        ]
+        .into_iter()
+        .map(|piece| crate::write_to_string(|out| self.write_border(out, piece)).len())
+        .max()
+        .unwrap_or(0)
+    }
+
+    /// Draw a table that has been built with a [`TableBuilder`]
+    fn draw_table(&self, w: &mut (impl fmt::Write + ?Sized), table: &TableBuilder) -> fmt::Result {
+        self.draw_layout(
+            w,
+            &table.layout(self.frame_widths()),
+            table.title_with_alignment(),
+        )
+    }
+
+    /// Draw a table that has been built with a [`TableBuilder`] into a new [`String`], which has
+    /// room for the whole table reserved up front
+    #[cfg(feature = "std")]
+    fn render_table(&self, table: &TableBuilder) -> String {
+        let layout = table.layout(self.frame_widths());
+        let cols = layout.widths.len();
+        let border = self.border_len();
+        // Every line is at most a border between each pair of columns plus the columns
+        // themselves, which are mostly text but may be drawn with a border between rows
+        let line = (cols + 1) * border + layout.widths.iter().sum::<usize>() * border + 1;
+        let lines = layout.heights().iter().sum::<usize>() + layout.rows.len() + 1;
+
+        let mut out = String::with_capacity(line * lines);
+        self.draw_layout(&mut out, &layout, table.title_with_alignment())
+            .expect("writing to a String can't fail");
+        out
+    }
+
+    /// Draw a table that has been split into lines, with `title` placed on its top line using its
+    /// alignment if it has one
+    fn draw_layout(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        layout: &Layout,
+        title: Option<(&str, Alignment)>,
+    ) -> fmt::Result {
+        let widths = &layout.widths;
+
+        let first = layout.rows.first().map(Vec::as_slice).unwrap_or_default();
+        let below = separated(first, widths.len());
+        let junctions = self.junctions(&[], first, widths);
+        match title {
+            Some(title) => self.write_titled_top_line(w, widths, &below, junctions, title)?,
+            None => self.write_top_line(w, widths, &below, junctions)?,
+        }
+        w.write_char('\n')?;
+
+        match layout.is_grid() {
+            true => self.draw_grid_rows(w, layout)?,
+            false => self.draw_rows(w, layout, widths, &layout.heights(), true)?,
+        }
+
+        let last = layout.rows.last().map(Vec::as_slice).unwrap_or_default();
+        let above = separated(last, widths.len());
+        let junctions = self.junctions(last, &[], widths);
+        self.write_bottom_line(w, widths, &above, junctions)
+    }
+
+    /// Draw each of the `rows` into `w` as soon as it arrives, using fixed column `widths` and
+    /// flushing `w` after every row
+    #[cfg(feature = "std")]
+    fn draw_stream<R: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        let separated = vec![true; widths.len().saturating_sub(1)];
+        let top =
+            crate::write_to_string(|out| self.write_top_line(out, widths, &separated, Vec::new()));
+        writeln!(w, "{}", top)?;
+        w.flush()?;
+
+        for (i, row) in rows.into_iter().enumerate() {
+            let mut table = TableBuilder::new();
+            table.row(row.into_iter().take(widths.len()));
+            for (column, &width) in widths.iter().enumerate() {
+                table.column_max_width(column, width);
+            }
+            let mut layout = table.layout(self.frame_widths());
+            // Short rows and narrow cells still take up the full width of every column
+            layout.widths = widths.to_vec();
+
+            let lines = crate::write_to_string(|out| {
+                if i > 0 {
+                    let line = Line::new(
+                        Piece::InnerHorizontal,
+                        Some((Piece::VerticalRight, Piece::VerticalLeft)),
+                        (&separated, &separated),
+                        &[],
+                    );
+                    self.write_line(out, &line, widths)?;
+                    out.push('\n');
+                }
+                self.draw_rows(out, &layout, widths, &layout.heights(), true)
+            });
+            w.write_all(lines.as_bytes())?;
+            w.flush()?;
+        }
+
+        let bottom = crate::write_to_string(|out| {
+            self.write_bottom_line(out, widths, &separated, Vec::new())
+        });
+        writeln!(w, "{}", bottom)?;
+        w.flush()
+    }
+
+    /// Draw the rows of `layout` and the rules between them, with columns of the given `widths`
+    /// and rows of the given `heights`, ending each line with a line break
+    ///
+    /// The left and right edges are only drawn if `edges` is set.
+    fn draw_rows(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        layout: &Layout,
+        widths: &[usize],
+        heights: &[usize],
+        edges: bool,
+    ) -> fmt::Result {
+        let cols = widths.len();
+        let inner = self.piece_width(Piece::InnerVertical);
+        let separated: Vec<_> = layout.rows.iter().map(|row| separated(row, cols)).collect();
+
+        for (i, row) in layout.rows.iter().enumerate() {
+            // A cell that spans several columns also covers the borders between them
+            let cell_width = |col: usize, span: usize| {
+                let end = (col + span).min(cols);
+                widths[col..end].iter().sum::<usize>() + span.saturating_sub(1) * inner
+            };
+            let nested = row
+                .iter()
+                .enumerate()
+                .map(|(col, cell)| match &cell.nested {
+                    Some(nested) => self
+                        .draw_nested(nested, cell_width(col, cell.span), heights[i])
+                        .map(Some),
+                    None => Ok(None),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for line in 0..heights[i] {
+                // The borders next to a rule of a nested table join up with it
+                let is_rule = |col: usize| {
+                    nested
+                        .get(col)
+                        .and_then(Option::as_ref)
+                        .is_some_and(|nested| nested.rules.get(line) == Some(&true))
+                };
+
+                let mut previous = None;
+                // Use the widths rather than the row so the columns a title added are also drawn
+                for col in 0..cols {
+                    let cell = row.get(col);
+                    // Columns covered by a cell to their left have already been drawn
+                    let span = cell.map_or(1, |cell| cell.span);
+                    if span == 0 {
+                        continue;
+                    }
+                    let left = previous.is_some_and(is_rule);
+                    let border = match (left, is_rule(col)) {
+                        (_, false) if col == 0 => Piece::Vertical,
+                        (_, true) if col == 0 => Piece::VerticalRight,
+                        (true, true) => Piece::Cross,
+                        (true, false) => Piece::VerticalLeft,
+                        (false, true) => Piece::VerticalRight,
+                        (false, false) => Piece::InnerVertical,
+                    };
+                    if col > 0 || edges {
+                        self.write_border(w, border)?;
+                    }
+
+                    let width = cell_width(col, span);
+                    match (cell, nested.get(col).and_then(Option::as_ref)) {
+                        (Some(cell), Some(nested)) => {
+                            let text = nested.lines.get(line).map_or("", String::as_str);
+                            cell.alignment.write_aligned(w, text, width)?;
+                        }
+                        (Some(cell), None) => {
+                            let text = layout.line(cell, line, heights[i]);
+                            color::write_painted(w, cell.color, cell.background, |w| {
+                                let text_width = cell.line_width(text);
+                                cell.alignment
+                                    .write_measured(w, text, text_width, width, cell.fill)
+                            })?;
+                        }
+                        (None, _) => write_spaces(w, width)?,
+                    }
+                    previous = Some(col);
+                }
+                if edges {
+                    match previous.is_some_and(is_rule) {
+                        true => self.write_border(w, Piece::VerticalLeft)?,
+                        false => self.write_border(w, Piece::Vertical)?,
+                    }
+                }
+                w.write_char('\n')?;
+            }
+
+            // Separate this row from the next one
+            if layout.is_ruled_after(i) {
+                let horizontal = match layout.is_around_body(i) {
+                    true => Piece::HeaderHorizontal,
+                    false => Piece::InnerHorizontal,
+                };
+                let next = &layout.rows[i + 1];
+                // Cells that carry on from this row into the next one aren't ruled off
+                let ruled: Vec<_> = next.iter().map(|cell| !cell.continued).collect();
+                let line = Line::new(
+                    horizontal,
+                    edges.then_some((Piece::VerticalRight, Piece::VerticalLeft)),
+                    (&separated[i], &separated[i + 1]),
+                    &ruled,
+                );
+                let junctions = self.junctions(row, next, widths);
+                self.write_line(w, &Line { junctions, ..line }, widths)?;
+                w.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw the rows of `layout` when every cell takes up exactly one slot of the grid, ending
+    /// each line with a line break
+    ///
+    /// Without any spans or nested tables every rule and border is the same on each row, so they
+    /// are only drawn once and copied, which makes a big difference for tables with thousands of
+    /// rows.
+    fn draw_grid_rows(&self, w: &mut (impl fmt::Write + ?Sized), layout: &Layout) -> fmt::Result {
+        let widths = &layout.widths;
+        let separated = vec![true; widths.len().saturating_sub(1)];
+        let rule = |horizontal: Piece| {
+            let line = Line::new(
+                horizontal,
+                Some((Piece::VerticalRight, Piece::VerticalLeft)),
+                (&separated, &separated),
+                &[],
+            );
+            crate::write_to_string(|out| self.write_line(out, &line, widths))
+        };
+        let inner_rule = rule(Piece::InnerHorizontal);
+        let header_rule = rule(Piece::HeaderHorizontal);
+        let vertical = crate::write_to_string(|out| self.write_border(out, Piece::Vertical));
+        let inner = crate::write_to_string(|out| self.write_border(out, Piece::InnerVertical));
+
+        for (i, (row, height)) in layout.rows.iter().zip(layout.heights()).enumerate() {
+            for line in 0..height {
+                w.write_str(&vertical)?;
+                for (col, &width) in widths.iter().enumerate() {
+                    if col > 0 {
+                        w.write_str(&inner)?;
+                    }
+                    match row.get(col) {
+                        Some(cell) => {
+                            let text = layout.line(cell, line, height);
+                            color::write_painted(w, cell.color, cell.background, |w| {
+                                let text_width = cell.line_width(text);
+                                cell.alignment
+                                    .write_measured(w, text, text_width, width, cell.fill)
+                            })?;
+                        }
+                        None => write_spaces(w, width)?,
+                    }
+                }
+                w.write_str(&vertical)?;
+                w.write_char('\n')?;
+            }
+
+            if layout.is_ruled_after(i) {
+                match layout.is_around_body(i) {
+                    true => w.write_str(&header_rule)?,
+                    false => w.write_str(&inner_rule)?,
+                }
+                w.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw a table nested inside a cell without its outer border, stretching its last column
+    /// and last row so that it fills `width` columns and `height` lines
+    fn draw_nested(
+        &self,
+        layout: &Layout,
+        width: usize,
+        height: usize,
+    ) -> Result<Nested, fmt::Error> {
+        let inner = self.piece_width(Piece::InnerVertical);
+        let mut widths = layout.widths.clone();
+        let natural = widths.iter().sum::<usize>() + widths.len().saturating_sub(1) * inner;
+        if let Some(last) = widths.last_mut() {
+            *last += width.saturating_sub(natural);
+        }
+        let mut heights = layout.heights();
+        if let Some(last) = heights.last_mut() {
+            *last += height.saturating_sub(layout.height());
+        }
+
+        let mut rules = Vec::new();
+        for (i, &height) in heights.iter().enumerate() {
+            rules.extend(iter::repeat_n(false, height));
+            if layout.is_ruled_after(i) {
+                rules.push(true);
+            }
+        }
+
+        let mut out = String::new();
+        self.draw_rows(&mut out, layout, &widths, &heights, false)?;
+        Ok(Nested {
+            lines: out.lines().map(String::from).collect(),
+            rules,
+        })
+    }
+
+    /// Get where the vertical lines of the tables nested in the cells of `above` and `below`
+    /// meet the horizontal line between them, as offsets from the left end of the line
+    fn junctions(
+        &self,
+        above: &[LayoutCell],
+        below: &[LayoutCell],
+        widths: &[usize],
+    ) -> Vec<(usize, Piece)> {
+        let inner = self.piece_width(Piece::InnerVertical);
+        let mut junctions: Vec<(usize, Piece)> = Vec::new();
+        for (row, piece) in [(above, Piece::HorizontalUp), (below, Piece::HorizontalDown)] {
+            for (col, cell) in row.iter().enumerate() {
+                let Some(nested) = &cell.nested else {
+                    continue;
+                };
+                let edge = match piece {
+                    Piece::HorizontalUp => nested.rows.last(),
+                    _ => nested.rows.first(),
+                };
+                let edge = edge.map(Vec::as_slice).unwrap_or_default();
+
+                let mut offset = widths[..col].iter().sum::<usize>() + col * inner;
+                let inner_cols = nested.widths.len();
+                for (width, separated) in nested.widths.iter().zip(separated(edge, inner_cols)) {
+                    offset += width;
+                    if separated {
+                        junctions.push((offset, piece));
+                    }
+                    offset += inner;
+                }
+            }
+        }
+
+        // Lines that meet from both sides cross over
+        junctions.sort_by_key(|&(offset, _)| offset);
+        junctions.dedup_by(|next, previous| {
+            let same = next.0 == previous.0;
+            if same && next.1 != previous.1 {
+                previous.1 = Piece::Cross;
+            }
+            same
+        });
+        junctions
+    }
+}
+
+/// A table drawn inside a cell of another table
+pub(crate) struct Nested {
+    /// Each line of the table
+    lines: Vec<String>,
+    /// Whether each line is a rule between two rows, which the borders on either side join up
+    /// with
+    rules: Vec<bool>,
+}
+
+/// Get whether each column of `row` is separated from the column to its right by a vertical
+/// line, which isn't the case where a cell spans both of them
+fn separated(row: &[LayoutCell], cols: usize) -> Vec<bool> {
+    (1..cols)
+        .map(|col| row.get(col).is_none_or(|cell| cell.span != 0))
+        .collect()
+}
+
+/// The entries of a slice, split into lines and measured so that they can be drawn in a single
+/// pass
+pub(crate) struct Entries<'a> {
+    /// The lines of each entry
+    lines: Vec<Vec<&'a str>>,
+    /// The width of the widest line, which every column is padded to
+    width: usize,
+    /// The number of lines in the tallest entry
+    height: usize,
+}
+
+impl<'a> Entries<'a> {
+    /// Split each of the `entries` into lines and measure them
+    fn new(entries: &'a [String]) -> Self {
+        let lines: Vec<Vec<_>> = entries
+            .iter()
+            .map(|entry| entry.lines().collect())
+            .collect();
+        let width = lines
+            .iter()
+            .flatten()
+            .map(|line| width::display_width(line))
+            .max()
+            .unwrap_or(0);
+        let height = lines.iter().map(Vec::len).max().unwrap_or(1);
+        Self {
+            lines,
+            width,
+            height,
+        }
+    }
+}
+
+/// What goes between two columns on a horizontal line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Join {
+    /// A junction or corner
+    Piece(Piece),
+    /// The horizontal line carries on through
+    Through,
+    /// Nothing, because a single cell covers both sides of the line
+    Blank,
+}
+
+/// A horizontal line across a table, either along its top or bottom edge or between two rows
+pub(crate) struct Line {
+    /// The piece that the line is drawn with
+    horizontal: Piece,
+    /// The piece at the left end of the line, if it has one
+    left: Option<Piece>,
+    /// The piece at the right end of the line, if it has one
+    right: Option<Piece>,
+    /// What goes between each pair of columns
+    joins: Vec<Join>,
+    /// Whether the line is drawn under each column, rather than being left blank because a cell
+    /// carries on across it
+    ruled: Vec<bool>,
+    /// Pieces that replace the horizontal line where the lines of a nested table meet it, as
+    /// offsets from the left end of the line
+    junctions: Vec<(usize, Piece)>,
+}
+
+impl Line {
+    /// Create a line of `horizontal` that ends with `ends` (if it has any), where `above` and
+    /// `below` are whether each column of the rows above and below the line are separated from
+    /// the next one and `ruled` is whether the line is drawn under each column (defaulting to
+    /// `true`)
+    fn new(
+        horizontal: Piece,
+        ends: Option<(Piece, Piece)>,
+        (above, below): (&[bool], &[bool]),
+        ruled: &[bool],
+    ) -> Self {
+        let cols = above.len().max(below.len());
+        let is_ruled = |col: usize| ruled.get(col).copied().unwrap_or(true);
+        let joins = (0..cols)
+            .map(|i| {
+                let up = above.get(i).copied().unwrap_or(false);
+                let down = below.get(i).copied().unwrap_or(false);
+                match (is_ruled(i), is_ruled(i + 1), up, down) {
+                    (true, true, true, true) => Join::Piece(Piece::Cross),
+                    (true, true, true, false) => Join::Piece(Piece::HorizontalUp),
+                    (true, true, false, true) => Join::Piece(Piece::HorizontalDown),
+                    (false, false, false, false) => Join::Blank,
+                    (false, false, _, _) => Join::Piece(Piece::InnerVertical),
+                    (false, true, true, true) => Join::Piece(Piece::VerticalRight),
+                    (false, true, true, false) => Join::Piece(Piece::BottomLeft),
+                    (false, true, false, true) => Join::Piece(Piece::TopLeft),
+                    (true, false, true, true) => Join::Piece(Piece::VerticalLeft),
+                    (true, false, true, false) => Join::Piece(Piece::BottomRight),
+                    (true, false, false, true) => Join::Piece(Piece::TopRight),
+                    (_, _, false, false) => Join::Through,
+                }
+            })
+            .collect();
+
+        // The line doesn't reach the edges if the cells next to them carry on across it
+        let last = cols.max(ruled.len().saturating_sub(1));
+        let edge = |ruled: bool, end: Piece| if ruled { end } else { Piece::Vertical };
+        Self {
+            horizontal,
+            left: ends.map(|(left, _)| edge(is_ruled(0), left)),
+            right: ends.map(|(_, right)| edge(is_ruled(last), right)),
+            joins,
+            ruled: ruled.to_vec(),
+            junctions: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        composite::CompositeBorderStyle,
+        styles,
+        table::{RowSeparator, SortOrder},
+        StringBorderStyle,
+    };
+
+    /// Draw the rows of `table` with both [`Frame::draw_grid_rows`] and [`Frame::draw_rows`] and
+    /// check that they are the same
+    fn assert_same_rows(style: &impl Frame, table: &TableBuilder) {
+        let layout = table.layout(style.frame_widths());
+        assert!(layout.is_grid());
+        let grid = crate::write_to_string(|w| style.draw_grid_rows(w, &layout));
+        let general = crate::write_to_string(|w| {
+            style.draw_rows(w, &layout, &layout.widths, &layout.heights(), true)
+        });
+        assert_eq!(grid, general);
+    }
+
+    /// Tables that are drawn with [`Frame::draw_grid_rows`]
+    fn grid_tables() -> Vec<TableBuilder> {
+        let people = [
+            ["Jon", "38", "1.5"],
+            ["Jake", "125", "12"],
+            ["Josh", "9", "0.25"],
+        ];
+        let mut tables = Vec::new();
+        for separator in [
+            RowSeparator::Every,
+            RowSeparator::HeaderOnly,
+            RowSeparator::Groups,
+            RowSeparator::None,
+        ] {
+            let mut table = TableBuilder::new();
+            table
+                .header(["Name", "Age", "Score"])
+                .rows(people)
+                .footer(["Total", "172", "13.75"])
+                .row_separator(separator);
+            tables.push(table);
+        }
+
+        let mut table = TableBuilder::new();
+        table
+            .rows(people)
+            .row(["Jim\nJones", "52"])
+            .align_numbers(true)
+            .zebra(Color::Blue)
+            .sort_by_column(1, SortOrder::Descending);
+        tables.push(table);
+
+        let mut table = TableBuilder::new();
+        table
+            .header(["a", "b"])
+            .styled_row([
+                crate::table::Cell::new("red").with_color(Color::Red),
+                crate::table::Cell::new("x"),
+            ])
+            .row_padding(1, 0)
+            .fill('.');
+        tables.push(table);
+        tables
+    }
+
+    #[test]
+    fn grid_rows_match_general_rows() {
+        let string = StringBorderStyle::new(" | ", "-", "-+-");
+        let composite = CompositeBorderStyle::new(styles::DOUBLE, styles::THIN);
+        for table in grid_tables() {
+            assert_same_rows(&styles::THIN, &table);
+            assert_same_rows(&styles::ASCII, &table);
+            assert_same_rows(&styles::DOUBLE_OUTER_THIN_INNER, &table);
+            assert_same_rows(&styles::BLANK, &table);
+            assert_same_rows(&string, &table);
+            assert_same_rows(&composite, &table);
+        }
+    }
+}
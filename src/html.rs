@@ -0,0 +1,386 @@
+//! Writing tables as HTML
+//!
+//! [`HtmlStyle`] implements [`BorderFormatter`], so every `format_*` method can produce an HTML
+//! `<table>` instead of a box-drawn one:
+//! ```rust
+//! use borderrs::{BorderFormatter, styles::HTML};
+//!
+//! let html: String = HTML.format_table_with_headers(&["a", "b"], &[vec![1, 2]]);
+//! ```
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "std")]
+use crate::table::EmptyBehavior;
+use crate::{
+    table::{BorderWidths, Layout, LayoutCell, TableBuilder},
+    width, Alignment, BorderFormatter,
+};
+
+/// Format as an HTML `<table>`
+///
+/// The header of the table is written into a `<thead>` using `<th>` cells, the footer into a
+/// `<tfoot>`, and the title into a `<caption>`.  Cells that span multiple lines have their lines
+/// joined with `<br>`, ANSI escape sequences are removed, and the text is escaped so that it can't
+/// inject any HTML.  Tables nested inside cells are written as `<table>` elements of their own,
+/// and trees are written as nested `<ul>` elements.
+///
+/// Classes can be added to the elements of the table so that they can be styled with CSS.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{html::HtmlStyle, table::TableBuilder, Alignment};
+/// let mut style = HtmlStyle::new();
+/// style.table_class("people").header_class("heading");
+///
+/// let table = TableBuilder::new()
+///     .header(["Name", "Age"])
+///     .row(["Jon", "38"])
+///     .row(["Jake", "25"])
+///     .alignments(&[Alignment::Left, Alignment::Right])
+///     .build(&style);
+///
+/// println!("{}", table);
+/// ```
+/// produces the output
+/// ```text
+/// <table class="people">
+///   <thead>
+///     <tr><th class="heading" style="text-align: left">Name</th><th class="heading" style="text-align: right">Age</th></tr>
+///   </thead>
+///   <tbody>
+///     <tr><td style="text-align: left">Jon</td><td style="text-align: right">38</td></tr>
+///     <tr><td style="text-align: left">Jake</td><td style="text-align: right">25</td></tr>
+///   </tbody>
+/// </table>
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HtmlStyle {
+    /// The class of the `<table>` element
+    table_class: Option<String>,
+    /// The class of each `<th>` element in the header
+    header_class: Option<String>,
+    /// The class of each `<tr>` element in the body
+    row_class: Option<String>,
+    /// The class of each `<td>` element in the body
+    cell_class: Option<String>,
+}
+
+impl HtmlStyle {
+    /// Create a new style without any classes
+    pub const fn new() -> Self {
+        Self {
+            table_class: None,
+            header_class: None,
+            row_class: None,
+            cell_class: None,
+        }
+    }
+
+    /// Set the class of the `<table>` element
+    pub fn table_class(&mut self, class: impl Display) -> &mut Self {
+        self.table_class = Some(class.to_string());
+        self
+    }
+
+    /// Set the class of each `<th>` element in the header
+    pub fn header_class(&mut self, class: impl Display) -> &mut Self {
+        self.header_class = Some(class.to_string());
+        self
+    }
+
+    /// Set the class of each `<tr>` element in the body
+    pub fn row_class(&mut self, class: impl Display) -> &mut Self {
+        self.row_class = Some(class.to_string());
+        self
+    }
+
+    /// Set the class of each `<td>` element in the body
+    pub fn cell_class(&mut self, class: impl Display) -> &mut Self {
+        self.cell_class = Some(class.to_string());
+        self
+    }
+
+    /// Write the opening tag of `element`, with `class`, a `colspan` and `rowspan` for `span`
+    /// and a `text-align` for `alignment` if they are given
+    fn write_open_tag(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        element: &str,
+        class: Option<&str>,
+        (colspan, rowspan): (usize, usize),
+        alignment: Option<Alignment>,
+    ) -> fmt::Result {
+        write!(w, "<{}", element)?;
+        if let Some(class) = class {
+            write!(w, " class=\"{}\"", escape(class))?;
+        }
+        if colspan > 1 {
+            write!(w, " colspan=\"{}\"", colspan)?;
+        }
+        if rowspan > 1 {
+            write!(w, " rowspan=\"{}\"", rowspan)?;
+        }
+        if let Some(alignment) = alignment {
+            let align = match alignment {
+                Alignment::Left => "left",
+                Alignment::Right => "right",
+                Alignment::Center => "center",
+            };
+            write!(w, " style=\"text-align: {}\"", align)?;
+        }
+        w.write_char('>')
+    }
+
+    /// Write the cells of a single row, using `element` (either `th` or `td`) for each cell
+    fn write_cells(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        row: &[LayoutCell],
+        cols: usize,
+        element: &str,
+        class: Option<&str>,
+    ) -> fmt::Result {
+        for col in 0..cols {
+            let cell = row.get(col);
+            // Columns covered by a cell to their left or above are part of that cell
+            let span = cell.map_or(1, |cell| cell.span);
+            if span == 0 || cell.is_some_and(|cell| cell.continued) {
+                continue;
+            }
+            let rows = cell.map_or(1, |cell| cell.rows);
+            let alignment = cell.map(|cell| cell.alignment).unwrap_or_default();
+            self.write_open_tag(w, element, class, (span, rows), Some(alignment))?;
+            if let Some(nested) = cell.and_then(|cell| cell.nested.as_ref()) {
+                w.write_char('\n')?;
+                self.write_layout(w, nested, None)?;
+            }
+            let lines = cell.map(|cell| cell.lines.as_slice()).unwrap_or_default();
+            for (i, line) in lines.iter().enumerate() {
+                if i > 0 {
+                    w.write_str("<br>")?;
+                }
+                w.write_str(&escape(&width::strip_ansi(line)))?;
+            }
+            write!(w, "</{}>", element)?;
+        }
+        Ok(())
+    }
+
+    /// Write a table that has been split into lines, with `title` as its caption if it has one
+    fn write_layout(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        layout: &Layout,
+        title: Option<&str>,
+    ) -> fmt::Result {
+        let Layout {
+            rows,
+            widths,
+            has_header,
+            has_footer,
+            ..
+        } = layout;
+        let cols = widths.len();
+
+        self.write_open_tag(w, "table", self.table_class.as_deref(), (1, 1), None)?;
+        w.write_char('\n')?;
+        if let Some(title) = title {
+            writeln!(w, "  <caption>{}</caption>", escape(title))?;
+        }
+
+        let mut rows = rows.iter();
+        if *has_header {
+            if let Some(header) = rows.next() {
+                w.write_str("  <thead>\n    <tr>")?;
+                let class = self.header_class.as_deref();
+                self.write_cells(w, header, cols, "th", class)?;
+                w.write_str("</tr>\n  </thead>\n")?;
+            }
+        }
+
+        let footer = if *has_footer { rows.next_back() } else { None };
+
+        w.write_str("  <tbody>\n")?;
+        for row in rows {
+            w.write_str("    ")?;
+            self.write_open_tag(w, "tr", self.row_class.as_deref(), (1, 1), None)?;
+            let class = self.cell_class.as_deref();
+            self.write_cells(w, row, cols, "td", class)?;
+            w.write_str("</tr>\n")?;
+        }
+        w.write_str("  </tbody>\n")?;
+
+        if let Some(footer) = footer {
+            w.write_str("  <tfoot>\n    <tr>")?;
+            let class = self.cell_class.as_deref();
+            self.write_cells(w, footer, cols, "td", class)?;
+            w.write_str("</tr>\n  </tfoot>\n")?;
+        }
+        w.write_str("</table>")
+    }
+}
+
+/// Write `node` as an `<li>` element indented by `depth` levels, followed by a `<ul>` of its
+/// children if it has any
+fn write_tree_node<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+    w: &mut (impl fmt::Write + ?Sized),
+    node: &'a T,
+    label: &impl Fn(&'a T) -> L,
+    children: &impl Fn(&'a T) -> C,
+    depth: usize,
+) -> fmt::Result {
+    let indent = "  ".repeat(depth);
+    let text = label(node).to_string();
+    let lines: Vec<_> = text
+        .lines()
+        .map(|line| escape(&width::strip_ansi(line)))
+        .collect();
+    write!(w, "{}<li>{}", indent, lines.join("<br>"))?;
+
+    let nodes: Vec<_> = children(node).into_iter().collect();
+    if !nodes.is_empty() {
+        writeln!(w, "\n{}  <ul>", indent)?;
+        for child in nodes {
+            write_tree_node(w, child, label, children, depth + 2)?;
+        }
+        write!(w, "{}  </ul>\n{}", indent, indent)?;
+    }
+    w.write_str("</li>\n")
+}
+
+/// Escape the characters that have a special meaning in HTML
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+impl BorderFormatter for HtmlStyle {
+    fn write_slice_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        alignment: Alignment,
+    ) -> fmt::Result {
+        TableBuilder::new()
+            .row(slice)
+            .alignments(&vec![alignment; slice.len()])
+            .write(w, self)
+    }
+
+    fn write_table_builder(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        table: &TableBuilder,
+    ) -> fmt::Result {
+        self.write_layout(
+            w,
+            &table.layout(BorderWidths::default()),
+            table.title.as_deref(),
+        )
+    }
+
+    fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> fmt::Result {
+        w.write_str("<ul>\n")?;
+        write_tree_node(w, root, &label, &children, 1)?;
+        w.write_str("</ul>")
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_rows<R: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        // The table is opened before any of the rows arrive, and each row is a `<tr>` of its own
+        let open = crate::write_to_string(|out| {
+            self.write_open_tag(out, "table", self.table_class.as_deref(), (1, 1), None)?;
+            out.push_str("\n  <tbody>");
+            Ok(())
+        });
+        writeln!(w, "{}", open)?;
+        w.flush()?;
+
+        for row in rows {
+            let mut table = TableBuilder::new();
+            table
+                .row(row.into_iter().take(widths.len()))
+                .empty_behavior(EmptyBehavior::Minimal);
+            for (column, &width) in widths.iter().enumerate() {
+                table.column_max_width(column, width);
+            }
+            let layout = table.layout(self.border_widths());
+
+            let line = crate::write_to_string(|out| {
+                out.push_str("    ");
+                self.write_open_tag(out, "tr", self.row_class.as_deref(), (1, 1), None)?;
+                let cells = layout.rows.first().map_or(&[][..], Vec::as_slice);
+                let class = self.cell_class.as_deref();
+                self.write_cells(out, cells, widths.len(), "td", class)?;
+                out.push_str("</tr>");
+                Ok(())
+            });
+            writeln!(w, "{}", line)?;
+            w.flush()?;
+        }
+
+        writeln!(w, "  </tbody>\n</table>")?;
+        w.flush()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::styles::HTML;
+
+    #[test]
+    fn rows_are_streamed_as_table_rows() {
+        let mut out = Vec::new();
+        HTML.stream_rows(&mut out, [["1", "<b>"]], &[1, 5]).unwrap();
+        let expected = "<table>\n  <tbody>\n    <tr><td style=\"text-align: right\">1</td>\
+            <td style=\"text-align: right\">&lt;b&gt;</td></tr>\n  </tbody>\n</table>\n";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_arrays_are_nested_tables() {
+        let html = HTML.format_json(&serde_json::json!({ "tags": ["a", "b"] }));
+        assert_eq!(html.matches("<table>").count(), 2);
+        assert!(!html.contains("&lt;"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_sequences_are_nested_tables() {
+        let html = HTML.format_serialize(&[("ports", vec![80, 443])]).unwrap();
+        assert_eq!(html.matches("<table>").count(), 3);
+        assert!(!html.contains("&lt;"));
+    }
+}
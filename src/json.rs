@@ -0,0 +1,154 @@
+//! Rendering [`serde_json::Value`]s as nested tables for [`BorderFormatter::format_json`], and
+//! building [`TableBuilder`]s from arrays of them
+//!
+//! Requires the `json` feature.
+use serde_json::{Map, Value};
+
+use crate::{
+    table::{Cell, TableBuilder},
+    Alignment,
+};
+
+/// Build the table that [`BorderFormatter::format_json`] draws for `value`, or `None` if it is
+/// drawn as text
+///
+/// Objects become key/value tables, arrays of objects become a table with a column for each key,
+/// and any other non-empty array becomes a table with one item per row.  Arrays and objects
+/// inside of them become nested tables.
+pub(crate) fn table(value: &Value) -> Option<TableBuilder> {
+    let mut table = TableBuilder::new();
+    match value {
+        Value::Array(items) if !items.is_empty() => match objects(items) {
+            Some(objects) => {
+                let columns = columns(&objects);
+                table
+                    .header(&columns)
+                    .alignments(&vec![Alignment::Left; columns.len()]);
+                for object in objects {
+                    table.styled_row(
+                        columns
+                            .iter()
+                            .map(|&key| object.get(key).map_or_else(Cell::default, cell)),
+                    );
+                }
+            }
+            None => {
+                table.alignments(&[Alignment::Left]);
+                for item in items {
+                    table.styled_row([cell(item)]);
+                }
+            }
+        },
+        Value::Object(entries) if !entries.is_empty() => {
+            table.alignments(&[Alignment::Right, Alignment::Left]);
+            for (key, value) in entries {
+                table.styled_row([Cell::new(key), cell(value)]);
+            }
+        }
+        _ => return None,
+    }
+    Some(table)
+}
+
+/// Get the text that a value which isn't drawn as a table is drawn as
+///
+/// Strings are drawn without their quotes, and everything else is drawn as JSON.
+pub(crate) fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Get every item of `items` as an object, if they are all objects
+fn objects(items: &[Value]) -> Option<Vec<&Map<String, Value>>> {
+    items.iter().map(Value::as_object).collect()
+}
+
+/// Build a table from an array of JSON values
+///
+/// An array of objects becomes a table with a column for each key, in the order that the keys are
+/// first seen, and an array of arrays becomes a table with a row for each inner array.  Any other
+/// array becomes a table with one value per row.  Like [`BorderFormatter::format_json`], strings
+/// are drawn without their quotes, non-empty arrays and objects become nested tables, and
+/// everything else is drawn as JSON.
+///
+/// [`BorderFormatter::format_json`]: crate::BorderFormatter::format_json
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, table::TableBuilder};
+/// # use serde_json::json;
+/// let people = json!([
+///     { "name": "Jon", "age": 38 },
+///     { "name": "Jake", "age": 25 },
+/// ]);
+///
+/// let table = TableBuilder::from(people.as_array().unwrap().as_slice());
+/// println!("{}", table.build(&styles::THIN));
+/// ```
+/// produces the output
+/// ```text
+/// ┌───┬────┐
+/// │age│name│
+/// ├───┼────┤
+/// │ 38│ Jon│
+/// ├───┼────┤
+/// │ 25│Jake│
+/// └───┴────┘
+/// ```
+impl From<&[Value]> for TableBuilder {
+    fn from(items: &[Value]) -> Self {
+        let mut table = TableBuilder::new();
+        if let Some(objects) = objects(items) {
+            let columns = columns(&objects);
+            table.header(&columns);
+            for object in objects {
+                table.styled_row(
+                    columns
+                        .iter()
+                        .map(|&key| object.get(key).map_or_else(Cell::default, cell)),
+                );
+            }
+        } else if let Some(arrays) = arrays(items) {
+            for array in arrays {
+                table.styled_row(array.iter().map(cell));
+            }
+        } else {
+            for item in items {
+                table.styled_row([cell(item)]);
+            }
+        }
+        table
+    }
+}
+
+/// Build a table from an array of JSON values
+///
+/// See [`TableBuilder::from`] for [`&[Value]`](Value) for more info
+impl From<Vec<Value>> for TableBuilder {
+    fn from(items: Vec<Value>) -> Self {
+        Self::from(items.as_slice())
+    }
+}
+
+/// Get every item of `items` as an array, if they are all arrays
+fn arrays(items: &[Value]) -> Option<Vec<&Vec<Value>>> {
+    items.iter().map(Value::as_array).collect()
+}
+
+/// Get the keys of `objects`, in the order that they are first seen
+fn columns<'a>(objects: &[&'a Map<String, Value>]) -> Vec<&'a str> {
+    let mut columns: Vec<&str> = Vec::new();
+    for key in objects.iter().flat_map(|object| object.keys()) {
+        if !columns.contains(&key.as_str()) {
+            columns.push(key);
+        }
+    }
+    columns
+}
+
+/// Make a cell for `value`, with a nested table for a non-empty array or object (see [`table`])
+fn cell(value: &Value) -> Cell {
+    table(value).map_or_else(|| Cell::new(text(value)), Cell::table)
+}
@@ -0,0 +1,161 @@
+//! Working out which box-drawing character to draw where lines of different weights meet, such
+//! as where the thin inner lines of a table meet its double outer border
+use Weight::{Double, Empty, Heavy, Light};
+
+/// The kind of line going out of a box-drawing character in one direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Weight {
+    /// There isn't a line
+    Empty,
+    /// A thin line, such as `─`
+    Light,
+    /// A thick line, such as `━`
+    Heavy,
+    /// A pair of lines, such as `═`
+    Double,
+}
+
+/// The index of the line going up in a set of arms
+pub(crate) const UP: usize = 0;
+/// The index of the line going right in a set of arms
+pub(crate) const RIGHT: usize = 1;
+/// The index of the line going down in a set of arms
+pub(crate) const DOWN: usize = 2;
+/// The index of the line going left in a set of arms
+pub(crate) const LEFT: usize = 3;
+
+/// The straight lines, corners (including the rounded ones), and junctions of the Box Drawing
+/// block, along with the weight of the line going up, right, down, and left from each of them
+const JUNCTIONS: [(char, [Weight; 4]); 101] = [
+    ('─', [Empty, Light, Empty, Light]),
+    ('━', [Empty, Heavy, Empty, Heavy]),
+    ('│', [Light, Empty, Light, Empty]),
+    ('┃', [Heavy, Empty, Heavy, Empty]),
+    ('┌', [Empty, Light, Light, Empty]),
+    ('┍', [Empty, Heavy, Light, Empty]),
+    ('┎', [Empty, Light, Heavy, Empty]),
+    ('┏', [Empty, Heavy, Heavy, Empty]),
+    ('┐', [Empty, Empty, Light, Light]),
+    ('┑', [Empty, Empty, Light, Heavy]),
+    ('┒', [Empty, Empty, Heavy, Light]),
+    ('┓', [Empty, Empty, Heavy, Heavy]),
+    ('└', [Light, Light, Empty, Empty]),
+    ('┕', [Light, Heavy, Empty, Empty]),
+    ('┖', [Heavy, Light, Empty, Empty]),
+    ('┗', [Heavy, Heavy, Empty, Empty]),
+    ('┘', [Light, Empty, Empty, Light]),
+    ('┙', [Light, Empty, Empty, Heavy]),
+    ('┚', [Heavy, Empty, Empty, Light]),
+    ('┛', [Heavy, Empty, Empty, Heavy]),
+    ('├', [Light, Light, Light, Empty]),
+    ('┝', [Light, Heavy, Light, Empty]),
+    ('┞', [Heavy, Light, Light, Empty]),
+    ('┟', [Light, Light, Heavy, Empty]),
+    ('┠', [Heavy, Light, Heavy, Empty]),
+    ('┡', [Heavy, Heavy, Light, Empty]),
+    ('┢', [Light, Heavy, Heavy, Empty]),
+    ('┣', [Heavy, Heavy, Heavy, Empty]),
+    ('┤', [Light, Empty, Light, Light]),
+    ('┥', [Light, Empty, Light, Heavy]),
+    ('┦', [Heavy, Empty, Light, Light]),
+    ('┧', [Light, Empty, Heavy, Light]),
+    ('┨', [Heavy, Empty, Heavy, Light]),
+    ('┩', [Heavy, Empty, Light, Heavy]),
+    ('┪', [Light, Empty, Heavy, Heavy]),
+    ('┫', [Heavy, Empty, Heavy, Heavy]),
+    ('┬', [Empty, Light, Light, Light]),
+    ('┭', [Empty, Light, Light, Heavy]),
+    ('┮', [Empty, Heavy, Light, Light]),
+    ('┯', [Empty, Heavy, Light, Heavy]),
+    ('┰', [Empty, Light, Heavy, Light]),
+    ('┱', [Empty, Light, Heavy, Heavy]),
+    ('┲', [Empty, Heavy, Heavy, Light]),
+    ('┳', [Empty, Heavy, Heavy, Heavy]),
+    ('┴', [Light, Light, Empty, Light]),
+    ('┵', [Light, Light, Empty, Heavy]),
+    ('┶', [Light, Heavy, Empty, Light]),
+    ('┷', [Light, Heavy, Empty, Heavy]),
+    ('┸', [Heavy, Light, Empty, Light]),
+    ('┹', [Heavy, Light, Empty, Heavy]),
+    ('┺', [Heavy, Heavy, Empty, Light]),
+    ('┻', [Heavy, Heavy, Empty, Heavy]),
+    ('┼', [Light, Light, Light, Light]),
+    ('┽', [Light, Light, Light, Heavy]),
+    ('┾', [Light, Heavy, Light, Light]),
+    ('┿', [Light, Heavy, Light, Heavy]),
+    ('╀', [Heavy, Light, Light, Light]),
+    ('╁', [Light, Light, Heavy, Light]),
+    ('╂', [Heavy, Light, Heavy, Light]),
+    ('╃', [Heavy, Light, Light, Heavy]),
+    ('╄', [Heavy, Heavy, Light, Light]),
+    ('╅', [Light, Light, Heavy, Heavy]),
+    ('╆', [Light, Heavy, Heavy, Light]),
+    ('╇', [Heavy, Heavy, Light, Heavy]),
+    ('╈', [Light, Heavy, Heavy, Heavy]),
+    ('╉', [Heavy, Light, Heavy, Heavy]),
+    ('╊', [Heavy, Heavy, Heavy, Light]),
+    ('╋', [Heavy, Heavy, Heavy, Heavy]),
+    ('═', [Empty, Double, Empty, Double]),
+    ('║', [Double, Empty, Double, Empty]),
+    ('╒', [Empty, Double, Light, Empty]),
+    ('╓', [Empty, Light, Double, Empty]),
+    ('╔', [Empty, Double, Double, Empty]),
+    ('╕', [Empty, Empty, Light, Double]),
+    ('╖', [Empty, Empty, Double, Light]),
+    ('╗', [Empty, Empty, Double, Double]),
+    ('╘', [Light, Double, Empty, Empty]),
+    ('╙', [Double, Light, Empty, Empty]),
+    ('╚', [Double, Double, Empty, Empty]),
+    ('╛', [Light, Empty, Empty, Double]),
+    ('╜', [Double, Empty, Empty, Light]),
+    ('╝', [Double, Empty, Empty, Double]),
+    ('╞', [Light, Double, Light, Empty]),
+    ('╟', [Double, Light, Double, Empty]),
+    ('╠', [Double, Double, Double, Empty]),
+    ('╡', [Light, Empty, Light, Double]),
+    ('╢', [Double, Empty, Double, Light]),
+    ('╣', [Double, Empty, Double, Double]),
+    ('╤', [Empty, Double, Light, Double]),
+    ('╥', [Empty, Light, Double, Light]),
+    ('╦', [Empty, Double, Double, Double]),
+    ('╧', [Light, Double, Empty, Double]),
+    ('╨', [Double, Light, Empty, Light]),
+    ('╩', [Double, Double, Empty, Double]),
+    ('╪', [Light, Double, Light, Double]),
+    ('╫', [Double, Light, Double, Light]),
+    ('╬', [Double, Double, Double, Double]),
+    ('╭', [Empty, Light, Light, Empty]),
+    ('╮', [Empty, Empty, Light, Light]),
+    ('╯', [Light, Empty, Empty, Light]),
+    ('╰', [Light, Light, Empty, Empty]),
+];
+
+/// Get the weight of the lines going up, right, down, and left from `c`, if it is a box-drawing
+/// line, corner, or junction
+pub(crate) fn arms(c: char) -> Option<[Weight; 4]> {
+    JUNCTIONS
+        .iter()
+        .find(|&&(junction, _)| junction == c)
+        .map(|&(_, arms)| arms)
+}
+
+/// Find the box-drawing character with exactly these `arms`, if there is one
+pub(crate) fn junction(arms: [Weight; 4]) -> Option<char> {
+    JUNCTIONS
+        .iter()
+        .find(|&&(_, other)| other == arms)
+        .map(|&(junction, _)| junction)
+}
+
+/// Get the weight of the line drawn by `c` in the direction of `arm`
+///
+/// Characters that aren't box-drawing characters are treated as thin lines, except for spaces
+/// (which aren't lines at all) and `=` (which is a double line).
+pub(crate) fn weight(c: char, arm: usize) -> Weight {
+    match (arms(c), c) {
+        (Some(arms), _) => arms[arm],
+        (None, ' ') => Empty,
+        (None, '=') => Double,
+        (None, _) => Light,
+    }
+}
@@ -1,48 +1,306 @@
 //! This crate allows the user to format many data structures in ways that look nicer to the
 //! end-user.
 //!
+//! Column widths are measured in terminal cells without any ANSI escape sequences, so colored
+//! text, CJK characters, and emoji can be used inside of cells without breaking the borders.
+//!
 //! The Wikipedia page on [Box-Drawing Characters](https://en.wikipedia.org/wiki/Box-drawing_character#Box_Drawing) has been quite helpful
 //!
 //! Currently, we support:
-//! - [`slice`]s with [`BorderFormatter::format_slice`]
+//! - [`slice`]s with [`BorderFormatter::format_slice`] (or
+//!   [`BorderFormatter::format_slice_compact`] for columns sized to their own entry,
+//!   [`BorderFormatter::format_slice_vertical`] for one entry per row, or
+//!   [`BorderFormatter::format_slice_chunked`] for a grid)
 //! - [`Iterator`]s with [`BorderFormatter::format_iter`]
-//! - [`HashMap`]s with [`BorderFormatter::format_hash_map`]
+//! - Rows from huge or never-ending iterators, written as they arrive, with
+//!   [`BorderFormatter::stream_rows`]
+//! - [`HashMap`]s with [`BorderFormatter::format_hash_map`] (or
+//!   [`BorderFormatter::format_nested_hash_map`] for maps of maps)
+//! - Key/value pairs (such as a [`BTreeMap`]) with [`BorderFormatter::format_pairs`] (or
+//!   [`BorderFormatter::format_card`] for the fields of a single record, and
+//!   [`BorderFormatter::format_pairs_horizontal`] for a strip of labelled values)
+//! - 2D tables (rows of cells) with [`BorderFormatter::format_table`]
+//! - Slices and tables of [`Option`]s or [`Result`]s, with a placeholder for the missing values,
+//!   with [`BorderFormatter::format_slice_opt`] and [`BorderFormatter::format_table_opt`]
+//! - Matrices (such as `&[[T; N]]`) with [`BorderFormatter::format_matrix`] (or
+//!   [`BorderFormatter::format_matrix_labeled`] for row and column labels)
+//! - Tables built up one row at a time with [`table::TableBuilder`] (with [`table::Columns`] to
+//!   format the cells of typed rows)
+//! - Quick tables written inline with the [`table!`] and [`row!`] macros
+//! - Message boxes with a title and padding with [`panel::Panel`]
+//! - Month calendars with [`BorderFormatter::format_month`]
+//! - Bar charts with [`BorderFormatter::format_bars`], histograms with
+//!   [`BorderFormatter::format_histogram`], and sparklines with
+//!   [`BorderFormatter::format_sparkline`]
+//! - Trees (such as directories) with [`BorderFormatter::format_tree`]
+//! - Structs that implement [`table::ToRow`] (which can be derived with the `derive` feature)
+//!   with [`BorderFormatter::format_rows`]
+//! - Records with shared keys (such as a [`Vec`] of maps) with
+//!   [`BorderFormatter::format_records`]
 //! - impl [`Display`] with [`BorderFormatter::format_display`]
-//! - impl [`Debug`] with [`BorderFormatter::format_debug`]
+//! - Paragraphs of text wrapped to a width with [`BorderFormatter::format_text`]
+//! - Horizontal rules between sections with [`BorderFormatter::format_rule`] and
+//!   [`BorderFormatter::format_titled_rule`]
+//! - impl [`Debug`] with [`BorderFormatter::format_debug`] (or
+//!   [`BorderFormatter::format_debug_pretty`] for the pretty-printed form)
+//! - Any `serde::Serialize` value with `BorderFormatter::format_serialize` (with the `serde`
+//!   feature)
+//! - JSON values with `BorderFormatter::format_json` (with the `json` feature)
+//! - The result of an SQLite query with `BorderFormatter::format_sqlite_rows` (with the `sqlite`
+//!   feature)
+//! - Arrow record batches with `BorderFormatter::format_record_batch` (with the `arrow` feature)
+//! - CSV records and arrays of JSON values turned into a [`table::TableBuilder`] with
+//!   `TableBuilder::from` and `TableBuilder::try_from` (with the `csv` and `json` features)
+//! - Already formatted blocks laid out next to each other with
+//!   [`BorderFormatter::format_side_by_side`], or joined so that they share a border
+//!   using [`merge::merge_vertical`] and [`merge::merge_horizontal`]
+//! - The differences between two lists of lines with [`BorderFormatter::format_diff`]
+//! - Lazily rendered [`Display`] adapters with [`BorderFormatter::display`],
+//!   [`BorderFormatter::slice`], and [`BorderFormatter::table`] (or
+//!   [`BorderFormatter::as_table_field`] for fields of log messages)
+//!
+//! Styles can be picked at runtime, such as by looking them up by name with [`styles::by_name`] or
+//! by letting [`styles::auto`] fall back to ASCII on terminals that can't show Unicode, and
+//! stored as trait objects using [`dynamic::DynBorderFormatter`].
+//!
+//! The frame of a table and the grid inside of it can be drawn in different styles using
+//! [`composite::CompositeBorderStyle`], such as a double frame around a thin grid.
+//!
+//! Besides the box-drawing styles, everything can also be written as a Markdown table using
+//! [`styles::MARKDOWN`] or as an HTML table using [`styles::HTML`], and as plain `Header: value`
+//! lines for screen readers using [`styles::ACCESSIBLE`].
 //!
+//! The `format_*` methods always produce something, even when the text contains control
+//! characters or the table can't fit within the width it was given.  The `try_*` methods, such as
+//! [`BorderFormatter::try_format_table`] and [`table::TableBuilder::try_build`], return an
+//! [`error::BorderError`] instead.
+//!
+//! Everything except formatting [`HashMap`]s and [`BorderFormatter::stream_rows`] works without the
+//! standard library, using only `core` and `alloc`, when the default `std` feature is disabled.
 //!
 //! # Usage Example
 //!
 //! ```rust
 //! use borderrs::{styles::THIN, BorderFormatter};
+//! # use std::collections::HashMap;
 //!
 //! let slice = [0, 1, 2, 3, 4];
 //! println!("{}", THIN.format_slice(&slice));
 //!
+//! # #[cfg(feature = "std")] {
 //! let mut map = HashMap::default();
 //! map.insert("Jon", 38);
 //! map.insert("Jake", 25);
 //! map.insert("Josh", 17);
 //! println!("{}", THIN.format_hash_map(&map));
+//! # }
+//!
+//! let table = vec![vec!["a", "b", "c"], vec!["d", "e"]];
+//! println!("{}", THIN.format_table(&table));
 //!
 //! println!("{}", THIN.format_display("hello"));
 //! println!("{}", THIN.format_debug("hello"));
 //! ```
-use std::{
-    collections::HashMap,
-    fmt::{Debug, Display},
+#![cfg_attr(not(feature = "std"), no_std)]
+// The docs link to things like `HashMap` that only exist with the `std` feature, so those links are
+// left as plain text without it
+#![cfg_attr(not(feature = "std"), allow(rustdoc::broken_intra_doc_links))]
+
+extern crate alloc;
+
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    rc::Rc,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
 };
+use core::{
+    fmt::{self, Debug, Display},
+    iter,
+};
+#[cfg(feature = "std")]
+use std::{collections::HashMap, io};
+
+use adapters::{Bordered, BorderedSlice, BorderedTable, TableField};
+use color::Color;
+use diff::Change;
+use error::BorderError;
+use frame::{Frame, Piece};
+use table::{BorderWidths, Cell, OptionalCell, RowSeparator, TableBuilder, ToRow};
+use tree::{Guides, TreeNode};
+use unicode_width::UnicodeWidthChar;
 
+pub mod accessible;
+pub mod adapters;
+mod calendar;
+mod chart;
+pub mod color;
+pub mod composite;
+#[cfg(feature = "csv")]
+mod delimited;
+mod diff;
+pub mod dynamic;
+pub mod error;
+mod frame;
+pub mod html;
+#[cfg(feature = "json")]
+mod json;
+mod junction;
+mod macros;
+pub mod markdown;
+pub mod merge;
+pub mod panel;
+#[cfg(feature = "arrow")]
+mod record_batch;
+#[cfg(feature = "serde")]
+pub mod ser;
+#[cfg(feature = "sqlite")]
+mod sqlite;
 pub mod styles;
+pub mod table;
+pub mod tree;
+mod width;
+
+/// Items used by the code generated by `#[derive(ToRow)]`, so that it works without `std`
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+}
+
+/// Controls where text is placed inside of a cell that is wider than the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    /// Place the text against the left edge of the cell
+    Left,
+    /// Place the text against the right edge of the cell
+    #[default]
+    Right,
+    /// Place the text in the middle of the cell, leaning left if it can't be exactly centered
+    Center,
+}
+
+impl Alignment {
+    /// Write `text` into `w`, padded with spaces so that it fills `width` columns
+    ///
+    /// The padding is based on [`width::display_width`] rather than the length of `text`, so any
+    /// ANSI escape sequences don't count towards the width.
+    fn write_aligned(
+        self,
+        w: &mut (impl fmt::Write + ?Sized),
+        text: &str,
+        width: usize,
+    ) -> fmt::Result {
+        self.write_filled(w, text, width, ' ')
+    }
+
+    /// Write `text` into `w`, padded with `fill` so that it fills `width` columns
+    ///
+    /// Lines without any text are padded with spaces instead, so that they stay blank.
+    fn write_filled(
+        self,
+        w: &mut (impl fmt::Write + ?Sized),
+        text: &str,
+        width: usize,
+        fill: char,
+    ) -> fmt::Result {
+        self.write_measured(w, text, width::display_width(text), width, fill)
+    }
+
+    /// Write `text`, which takes up `text_width` columns, into `w`, padded with `fill` so that it
+    /// fills `width` columns
+    fn write_measured(
+        self,
+        w: &mut (impl fmt::Write + ?Sized),
+        text: &str,
+        text_width: usize,
+        width: usize,
+        fill: char,
+    ) -> fmt::Result {
+        let fill = if text.is_empty() { ' ' } else { fill };
+        let padding = width.saturating_sub(text_width);
+        let (left, right) = match self {
+            Alignment::Left => (0, padding),
+            Alignment::Right => (padding, 0),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+        };
+        write_repeated(w, fill, left)?;
+        w.write_str(text)?;
+        write_repeated(w, fill, right)
+    }
+}
+
+/// Write `count` spaces into `w`
+fn write_spaces(w: &mut (impl fmt::Write + ?Sized), count: usize) -> fmt::Result {
+    write_repeated(w, ' ', count)
+}
+
+/// Write `c` into `w` `count` times
+fn write_repeated(w: &mut (impl fmt::Write + ?Sized), c: char, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        w.write_char(c)?;
+    }
+    Ok(())
+}
 
-/// Represents a simple border style where all lines use the same format (determined by the values
-/// in the struct)
+/// Represents a simple border style where each kind of line uses a single character (determined
+/// by the values in the struct)
+///
+/// The lines around the outside of the table can use different characters from the lines inside
+/// of it, see [`styles::DOUBLE_OUTER_THIN_INNER`].
+///
+/// Custom styles can be created using [`SimpleBorderStyle::new`].
+///
+/// With the `serde` feature, styles can be serialized and deserialized so that they can be
+/// loaded from config files.  Every piece has to be given, but `border_color` can be left out.
+///
+/// For example,
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// # use borderrs::{color::Color, styles, SimpleBorderStyle};
+/// let style: SimpleBorderStyle = serde_json::from_str(r#"{
+///     "vertical": "│", "horizontal": "─",
+///     "inner_vertical": "┆", "inner_horizontal": "┄", "header_horizontal": "━",
+///     "horizontal_up": "┴", "horizontal_down": "┬",
+///     "vertical_right": "├", "vertical_left": "┤",
+///     "top_left": "┌", "top_right": "┐",
+///     "bottom_left": "└", "bottom_right": "┘",
+///     "cross": "┼",
+///     "border_color": { "rgb": [255, 128, 0] }
+/// }"#).unwrap();
+/// assert_eq!(
+///     style,
+///     styles::THIN
+///         .with_inner_vertical('┆')
+///         .with_inner_horizontal('┄')
+///         .with_header_horizontal('━')
+///         .with_border_color(Color::Rgb(255, 128, 0))
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimpleBorderStyle {
-    /// Used as a vertical separator
+    /// Used as the vertical line on the left and right edges
     vertical: char,
-    /// Used as the horizontal separator
+    /// Used as the horizontal line on the top and bottom edges
     horizontal: char,
 
+    /// Used as a vertical separator between columns
+    inner_vertical: char,
+    /// Used as the horizontal separator between rows
+    inner_horizontal: char,
+    /// Used as the horizontal separator between the header and the first row
+    header_horizontal: char,
+
     /// Used when there is a line connecting up, left, and right
     horizontal_up: char,
     /// Used when there is a line connecting down, left, and right
@@ -65,9 +323,294 @@ pub struct SimpleBorderStyle {
 
     /// Used where ther is a line connecting in every direction
     cross: char,
+
+    /// The color that the border is drawn in
+    #[cfg_attr(feature = "serde", serde(default))]
+    border_color: Option<Color>,
+}
+
+impl SimpleBorderStyle {
+    /// Create a style that uses `vertical` for every vertical line, `horizontal` for every
+    /// horizontal line, and `junction` for every corner and junction
+    ///
+    /// The individual pieces can then be changed using the `with_*` methods, which can also be
+    /// used to make a modified copy of one of the [`styles`].
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{BorderFormatter, SimpleBorderStyle};
+    /// const STYLE: SimpleBorderStyle = SimpleBorderStyle::new('!', '~', '*')
+    ///     .with_header_horizontal('=');
+    ///
+    /// println!("{}", STYLE.format_table_with_headers(&["a", "b"], &[vec![1, 2]]));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// *~*~*
+    /// !a!b!
+    /// *=*=*
+    /// !1!2!
+    /// *~*~*
+    /// ```
+    pub const fn new(vertical: char, horizontal: char, junction: char) -> Self {
+        Self {
+            vertical,
+            horizontal,
+
+            inner_vertical: vertical,
+            inner_horizontal: horizontal,
+            header_horizontal: horizontal,
+
+            horizontal_up: junction,
+            horizontal_down: junction,
+
+            vertical_right: junction,
+            vertical_left: junction,
+
+            top_left: junction,
+            top_right: junction,
+
+            bottom_left: junction,
+            bottom_right: junction,
+
+            cross: junction,
+
+            border_color: None,
+        }
+    }
+
+    /// Get the character used for `piece`
+    fn piece(&self, piece: Piece) -> char {
+        match piece {
+            Piece::Vertical => self.vertical,
+            Piece::Horizontal => self.horizontal,
+            Piece::InnerVertical => self.inner_vertical,
+            Piece::InnerHorizontal => self.inner_horizontal,
+            Piece::HeaderHorizontal => self.header_horizontal,
+            Piece::HorizontalUp => self.horizontal_up,
+            Piece::HorizontalDown => self.horizontal_down,
+            Piece::VerticalRight => self.vertical_right,
+            Piece::VerticalLeft => self.vertical_left,
+            Piece::TopLeft => self.top_left,
+            Piece::TopRight => self.top_right,
+            Piece::BottomLeft => self.bottom_left,
+            Piece::BottomRight => self.bottom_right,
+            Piece::Cross => self.cross,
+        }
+    }
+
+    /// Draw the border in `color`, leaving the contents of the cells as they are
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{color::Color, styles, BorderFormatter};
+    /// let style = styles::THIN.with_border_color(Color::Cyan);
+    /// println!("{}", style.format_slice(&["Hello", "world"]));
+    /// ```
+    pub const fn with_border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    /// Use `c` as the vertical line on the left and right edges
+    pub const fn with_vertical(mut self, c: char) -> Self {
+        self.vertical = c;
+        self
+    }
+
+    /// Use `c` as the horizontal line on the top and bottom edges
+    pub const fn with_horizontal(mut self, c: char) -> Self {
+        self.horizontal = c;
+        self
+    }
+
+    /// Use `c` as the vertical separator between columns
+    pub const fn with_inner_vertical(mut self, c: char) -> Self {
+        self.inner_vertical = c;
+        self
+    }
+
+    /// Use `c` as the horizontal separator between rows
+    pub const fn with_inner_horizontal(mut self, c: char) -> Self {
+        self.inner_horizontal = c;
+        self
+    }
+
+    /// Use `c` as the horizontal separator between the header and the first row
+    pub const fn with_header_horizontal(mut self, c: char) -> Self {
+        self.header_horizontal = c;
+        self
+    }
+
+    /// Use `c` as the junction connecting up, left, and right
+    pub const fn with_horizontal_up(mut self, c: char) -> Self {
+        self.horizontal_up = c;
+        self
+    }
+
+    /// Use `c` as the junction connecting down, left, and right
+    pub const fn with_horizontal_down(mut self, c: char) -> Self {
+        self.horizontal_down = c;
+        self
+    }
+
+    /// Use `c` as the junction connecting up, down, and right
+    pub const fn with_vertical_right(mut self, c: char) -> Self {
+        self.vertical_right = c;
+        self
+    }
+
+    /// Use `c` as the junction connecting up, down, and left
+    pub const fn with_vertical_left(mut self, c: char) -> Self {
+        self.vertical_left = c;
+        self
+    }
+
+    /// Use `c` as the top-left corner
+    pub const fn with_top_left(mut self, c: char) -> Self {
+        self.top_left = c;
+        self
+    }
+
+    /// Use `c` as the top-right corner
+    pub const fn with_top_right(mut self, c: char) -> Self {
+        self.top_right = c;
+        self
+    }
+
+    /// Use `c` as the bottom-left corner
+    pub const fn with_bottom_left(mut self, c: char) -> Self {
+        self.bottom_left = c;
+        self
+    }
+
+    /// Use `c` as the bottom-right corner
+    pub const fn with_bottom_right(mut self, c: char) -> Self {
+        self.bottom_right = c;
+        self
+    }
+
+    /// Use `c` as the junction connecting in every direction
+    pub const fn with_cross(mut self, c: char) -> Self {
+        self.cross = c;
+        self
+    }
+
+    /// Get the color that the border is drawn in, if it has one
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{color::Color, styles};
+    /// assert_eq!(styles::THIN.border_color(), None);
+    /// assert_eq!(
+    ///     styles::THIN.with_border_color(Color::Cyan).border_color(),
+    ///     Some(Color::Cyan)
+    /// );
+    /// ```
+    pub const fn border_color(&self) -> Option<Color> {
+        self.border_color
+    }
+
+    /// Get the character used as the vertical line on the left and right edges
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::styles;
+    /// assert_eq!(styles::DOUBLE.vertical(), '║');
+    /// assert_eq!(styles::DOUBLE_OUTER_THIN_INNER.inner_vertical(), '│');
+    /// ```
+    pub const fn vertical(&self) -> char {
+        self.vertical
+    }
+
+    /// Get the character used as the horizontal line on the top and bottom edges
+    pub const fn horizontal(&self) -> char {
+        self.horizontal
+    }
+
+    /// Get the character used as the vertical separator between columns
+    pub const fn inner_vertical(&self) -> char {
+        self.inner_vertical
+    }
+
+    /// Get the character used as the horizontal separator between rows
+    pub const fn inner_horizontal(&self) -> char {
+        self.inner_horizontal
+    }
+
+    /// Get the character used as the horizontal separator between the header and the first row
+    pub const fn header_horizontal(&self) -> char {
+        self.header_horizontal
+    }
+
+    /// Get the character used as the junction connecting up, left, and right
+    pub const fn horizontal_up(&self) -> char {
+        self.horizontal_up
+    }
+
+    /// Get the character used as the junction connecting down, left, and right
+    pub const fn horizontal_down(&self) -> char {
+        self.horizontal_down
+    }
+
+    /// Get the character used as the junction connecting up, down, and right
+    pub const fn vertical_right(&self) -> char {
+        self.vertical_right
+    }
+
+    /// Get the character used as the junction connecting up, down, and left
+    pub const fn vertical_left(&self) -> char {
+        self.vertical_left
+    }
+
+    /// Get the character used as the top-left corner
+    pub const fn top_left(&self) -> char {
+        self.top_left
+    }
+
+    /// Get the character used as the top-right corner
+    pub const fn top_right(&self) -> char {
+        self.top_right
+    }
+
+    /// Get the character used as the bottom-left corner
+    pub const fn bottom_left(&self) -> char {
+        self.bottom_left
+    }
+
+    /// Get the character used as the bottom-right corner
+    pub const fn bottom_right(&self) -> char {
+        self.bottom_right
+    }
+
+    /// Get the character used as the junction connecting in every direction
+    pub const fn cross(&self) -> char {
+        self.cross
+    }
 }
 
 /// Used to control the formatting for each type of BorderStyle
+///
+/// Every `format_*` method has a `write_*` counterpart which writes the output straight into a
+/// [`fmt::Write`] instead of allocating a new [`String`].
+///
+/// It is also implemented for references, [`Box`]es, [`Rc`]s, and [`Arc`]s of formatters
+/// (including trait objects of [`dynamic::DynBorderFormatter`]), so they can be passed to
+/// anything that takes a formatter without dereferencing them first:
+/// ```rust
+/// use borderrs::{dynamic::DynBorderFormatter, styles, BorderFormatter};
+/// use std::sync::Arc;
+///
+/// fn report(style: impl BorderFormatter) -> String {
+///     style.format_slice(&["ok", "ok", "failed"])
+/// }
+///
+/// let shared = Arc::new(styles::ROUNDED);
+/// let picked: Box<dyn DynBorderFormatter> = Box::new(styles::ASCII);
+/// assert_eq!(report(&styles::THIN), report(styles::THIN));
+/// assert_eq!(report(shared.clone()), report(styles::ROUNDED));
+/// assert_eq!(report(picked), report(styles::ASCII));
+/// ```
 pub trait BorderFormatter {
     /// Format a slice into an horizontal table
     ///
@@ -85,22 +628,311 @@ pub trait BorderFormatter {
     /// └──────┴──────┴──────┴──────┴──────┴──────┴──────┘
     /// ```
     ///
-    fn format_slice(&self, slice: &[impl Display]) -> String;
+    fn format_slice(&self, slice: &[impl Display]) -> String {
+        write_to_string(|w| self.write_slice(w, slice))
+    }
+
+    /// Write a slice into `w` as an horizontal table
+    ///
+    /// The default implementation calls [`Self::write_slice_aligned`] using
+    /// [`Alignment::Right`].
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let mut out = String::new();
+    /// styles::THIN.write_slice(&mut out, &[1, 2, 3]).unwrap();
+    /// println!("{}", out);
+    /// ```
+    ///
+    /// See [`Self::format_slice`] for more info
+    fn write_slice(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+    ) -> fmt::Result {
+        self.write_slice_aligned(w, slice, Alignment::Right)
+    }
 
-    /// Format an iterator into a horizontal table
+    /// Format a slice into an horizontal table, aligning each entry using `alignment`
     ///
-    /// The default implementation collects the `iter` into a [`Vec`] and passes it to
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, Alignment, BorderFormatter};
+    /// println!(
+    ///     "{}",
+    ///     styles::THIN.format_slice_aligned(&["Hello", "world", "how", "are", "you"], Alignment::Center)
+    /// );
+    /// ```
+    /// ```text
+    /// ┌─────┬─────┬─────┬─────┬─────┐
+    /// │Hello│world│ how │ are │ you │
+    /// └─────┴─────┴─────┴─────┴─────┘
+    /// ```
+    fn format_slice_aligned(&self, slice: &[impl Display], alignment: Alignment) -> String {
+        write_to_string(|w| self.write_slice_aligned(w, slice, alignment))
+    }
+
+    /// Write a slice into `w` as an horizontal table, aligning each entry using `alignment`
+    ///
+    /// See [`Self::format_slice_aligned`] for example and more info
+    fn write_slice_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        alignment: Alignment,
+    ) -> fmt::Result;
+
+    /// Format a slice into an horizontal table where each column is only as wide as its own
+    /// entry
+    ///
+    /// Unlike [`Self::format_slice`], one long entry doesn't make every other column just as
+    /// wide.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// println!(
+    ///     "{}",
+    ///     styles::THIN.format_slice_compact(&["Hi", "a much longer entry", "ok"])
+    /// );
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌──┬───────────────────┬──┐
+    /// │Hi│a much longer entry│ok│
+    /// └──┴───────────────────┴──┘
+    /// ```
+    fn format_slice_compact(&self, slice: &[impl Display]) -> String {
+        write_to_string(|w| self.write_slice_compact(w, slice))
+    }
+
+    /// Write a slice into `w` as an horizontal table where each column is only as wide as its
+    /// own entry
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_slice_compact`] for example and more info
+    fn write_slice_compact(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+    ) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            TableBuilder::new()
+                .row(slice)
+                .alignments(&vec![Alignment::Right; slice.len()]),
+        )
+    }
+
+    /// Format a slice of values that may be missing, such as [`Option`]s or [`Result`]s, into an
+    /// horizontal table, drawing `placeholder` in place of each missing value
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let parsed: Vec<Result<i32, _>> = ["1", "two", "3"].iter().map(|s| s.parse()).collect();
+    /// println!("{}", styles::THIN.format_slice_opt(&parsed, "<error>"));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌───────┬───────┬───────┐
+    /// │      1│<error>│      3│
+    /// └───────┴───────┴───────┘
+    /// ```
+    fn format_slice_opt(&self, slice: &[impl OptionalCell], placeholder: impl Display) -> String {
+        write_to_string(|w| self.write_slice_opt(w, slice, placeholder))
+    }
+
+    /// Write a slice of values that may be missing into `w` as an horizontal table, drawing
+    /// `placeholder` in place of each missing value
+    ///
+    /// The default implementation calls [`Self::write_slice`] with the placeholder filled in.
+    ///
+    /// See [`Self::format_slice_opt`] for example and more info
+    fn write_slice_opt(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl OptionalCell],
+        placeholder: impl Display,
+    ) -> fmt::Result {
+        let placeholder = placeholder.to_string();
+        let slice: Vec<_> = slice
+            .iter()
+            .map(|cell| or_placeholder(cell, &placeholder))
+            .collect();
+        self.write_slice(w, &slice)
+    }
+
+    /// Format a slice into a vertical table, with one entry per row
+    ///
+    /// This is useful for long slices that would be too wide to fit in the terminal when using
     /// [`Self::format_slice`].
     ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// println!(
+    ///     "{}",
+    ///     styles::THIN.format_slice_vertical(&["Hello", "world", "how", "are", "you"])
+    /// );
+    /// ```
+    /// ```text
+    /// ┌─────┐
+    /// │Hello│
+    /// ├─────┤
+    /// │world│
+    /// ├─────┤
+    /// │  how│
+    /// ├─────┤
+    /// │  are│
+    /// ├─────┤
+    /// │  you│
+    /// └─────┘
+    /// ```
+    fn format_slice_vertical(&self, slice: &[impl Display]) -> String {
+        write_to_string(|w| self.write_slice_vertical(w, slice))
+    }
+
+    /// Write a slice into `w` as a vertical table, with one entry per row
+    ///
+    /// The default implementation calls [`Self::write_table`] with each entry in its own row.
+    ///
+    /// See [`Self::format_slice_vertical`] for example and more info
+    fn write_slice_vertical(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+    ) -> fmt::Result {
+        self.write_table(w, &slice.iter().map(|v| vec![v]).collect::<Vec<_>>())
+    }
+
+    /// Format a slice as a grid with `columns` entries per row, so that long slices wrap onto
+    /// several rows rather than making one very wide row
+    ///
+    /// Like [`Self::format_slice`], every column has the same width.  The last row is padded with
+    /// empty cells if the slice doesn't divide evenly, and a `columns` of zero is treated as one.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let slice: Vec<_> = (1..=10).collect();
+    /// println!("{}", styles::THIN.format_slice_chunked(&slice, 4));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌──┬──┬──┬──┐
+    /// │ 1│ 2│ 3│ 4│
+    /// ├──┼──┼──┼──┤
+    /// │ 5│ 6│ 7│ 8│
+    /// ├──┼──┼──┼──┤
+    /// │ 9│10│  │  │
+    /// └──┴──┴──┴──┘
+    /// ```
+    fn format_slice_chunked(&self, slice: &[impl Display], columns: usize) -> String {
+        write_to_string(|w| self.write_slice_chunked(w, slice, columns))
+    }
+
+    /// Write a slice into `w` as a grid with `columns` entries per row
+    ///
+    /// The default implementation calls [`Self::write_matrix_uniform`] with each chunk of the
+    /// slice as a row.
+    ///
+    /// See [`Self::format_slice_chunked`] for example and more info
+    fn write_slice_chunked(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        columns: usize,
+    ) -> fmt::Result {
+        let rows: Vec<_> = slice.chunks(columns.max(1)).collect();
+        self.write_matrix_uniform(w, &rows)
+    }
+
+    /// Format an iterator into a horizontal table
+    ///
     /// See [`Self::format_slice`] for example and more info
     fn format_iter(&self, iter: impl Iterator<Item = impl Display>) -> String {
-        self.format_slice(&iter.collect::<Vec<_>>())
+        write_to_string(|w| self.write_iter(w, iter))
+    }
+
+    /// Write an iterator into `w` as a horizontal table
+    ///
+    /// The default implementation collects the `iter` into a [`Vec`] and passes it to
+    /// [`Self::write_slice`].
+    ///
+    /// See [`Self::format_slice`] for example and more info
+    fn write_iter(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        iter: impl Iterator<Item = impl Display>,
+    ) -> fmt::Result {
+        self.write_slice(w, &iter.collect::<Vec<_>>())
+    }
+
+    /// Write each of the `rows` into `w` as soon as it arrives, using fixed column `widths`
+    ///
+    /// Unlike the other methods, which need every row before they can measure the columns, this
+    /// writes the top of the table straight away and then each row as it is produced (flushing
+    /// `w` after each one), so it works for huge or never-ending iterators such as logs.  Cells
+    /// that are too wide for their column are truncated, and cells past the last column are left
+    /// out.
+    ///
+    /// Every style in this crate writes one row at a time.  The default implementation, which is
+    /// only used by styles defined elsewhere, collects every row and calls
+    /// [`Self::write_table_builder`] instead, so it doesn't write anything until `rows` runs out,
+    /// and never finishes for an iterator that doesn't.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let rows = (1..=3).map(|i| [i.to_string(), "#".repeat(i)]);
+    /// styles::THIN.stream_rows(&mut std::io::stdout(), rows, &[3, 5]).unwrap();
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌───┬─────┐
+    /// │  1│    #│
+    /// ├───┼─────┤
+    /// │  2│   ##│
+    /// ├───┼─────┤
+    /// │  3│  ###│
+    /// └───┴─────┘
+    /// ```
+    #[cfg(feature = "std")]
+    fn stream_rows<R: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        let mut table = TableBuilder::new();
+        table.rows(rows.into_iter().map(|row| {
+            // Short rows still fill every column
+            let mut cells: Vec<_> = row
+                .into_iter()
+                .take(widths.len())
+                .map(|cell| cell.to_string())
+                .collect();
+            cells.resize(widths.len(), String::new());
+            cells
+        }));
+        for (column, &width) in widths.iter().enumerate() {
+            table
+                .column_min_width(column, width)
+                .column_max_width(column, width);
+        }
+        let out = write_to_string(|out| self.write_table_builder(out, &table));
+        writeln!(w, "{}", out)?;
+        w.flush()
     }
 
     /// Format a [`HashMap`] as a table using `Key` and `Value` as headers
     ///
-    /// The default implementation calls [`Self::format_hash_map_headers`] with `"Key"` as the key
-    /// header and `"Value"` as the value header.
+    /// The rows are in the iteration order of the map, which can change from run to run, so use
+    /// [`Self::format_hash_map_sorted`] if the output needs to be the same every time.
     ///
     /// For example,
     /// ```rust
@@ -126,8 +958,24 @@ pub trait BorderFormatter {
     /// │ Jake│   25│
     /// └─────┴─────┘
     /// ```
+    #[cfg(feature = "std")]
     fn format_hash_map(&self, map: &HashMap<impl Display, impl Display>) -> String {
-        self.format_hash_map_headers(map, "Key", "Value")
+        write_to_string(|w| self.write_hash_map(w, map))
+    }
+
+    /// Write a [`HashMap`] into `w` as a table using `Key` and `Value` as headers
+    ///
+    /// The default implementation calls [`Self::write_hash_map_headers`] with `"Key"` as the key
+    /// header and `"Value"` as the value header.
+    ///
+    /// See [`Self::format_hash_map`] for example and more info
+    #[cfg(feature = "std")]
+    fn write_hash_map(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        map: &HashMap<impl Display, impl Display>,
+    ) -> fmt::Result {
+        self.write_hash_map_headers(w, map, "Key", "Value")
     }
 
     /// Format a [`HashMap`] as a table using given headers
@@ -161,218 +1009,2842 @@ pub trait BorderFormatter {
     /// │ Jake│   25│
     /// └─────┴─────┘
     /// ```
+    #[cfg(feature = "std")]
     fn format_hash_map_headers(
         &self,
         map: &HashMap<impl Display, impl Display>,
-        value_header: impl AsRef<str>,
         key_header: impl AsRef<str>,
-    ) -> String;
+        value_header: impl AsRef<str>,
+    ) -> String {
+        write_to_string(|w| self.write_hash_map_headers(w, map, key_header, value_header))
+    }
+
+    /// Write a [`HashMap`] into `w` as a table using given headers
+    ///
+    /// The default implementation calls [`Self::write_pairs_headers`] with the entries of `map`.
+    ///
+    /// See [`Self::format_hash_map_headers`] for example and more info
+    #[cfg(feature = "std")]
+    fn write_hash_map_headers(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        map: &HashMap<impl Display, impl Display>,
+        key_header: impl AsRef<str>,
+        value_header: impl AsRef<str>,
+    ) -> fmt::Result {
+        self.write_pairs_headers(w, map, key_header, value_header)
+    }
+
+    /// Format a [`HashMap`] as a table using `Key` and `Value` as headers, with the rows sorted
+    /// by key
+    ///
+    /// Unlike [`Self::format_hash_map`], the output is the same every time, which makes it
+    /// suitable for snapshot tests.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// # use std::collections::HashMap;
+    /// let mut map = HashMap::default();
+    ///
+    /// map.insert("Jon", 38);
+    /// map.insert("Jake", 25);
+    /// map.insert("Josh", 17);
+    ///
+    /// println!("{}", styles::THIN.format_hash_map_sorted(&map));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬─────┐
+    /// │ Key│Value│
+    /// ├────┼─────┤
+    /// │Jake│   25│
+    /// ├────┼─────┤
+    /// │ Jon│   38│
+    /// ├────┼─────┤
+    /// │Josh│   17│
+    /// └────┴─────┘
+    /// ```
+    #[cfg(feature = "std")]
+    fn format_hash_map_sorted(&self, map: &HashMap<impl Display + Ord, impl Display>) -> String {
+        write_to_string(|w| self.write_hash_map_sorted(w, map))
+    }
+
+    /// Write a [`HashMap`] into `w` as a table using `Key` and `Value` as headers, with the rows
+    /// sorted by key
+    ///
+    /// The default implementation calls [`Self::write_pairs`] with the sorted entries of `map`.
+    ///
+    /// See [`Self::format_hash_map_sorted`] for example and more info
+    #[cfg(feature = "std")]
+    fn write_hash_map_sorted(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        map: &HashMap<impl Display + Ord, impl Display>,
+    ) -> fmt::Result {
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_by_key(|&(key, _)| key);
+        self.write_pairs(w, entries)
+    }
+
+    /// Format a [`HashMap`] as a table using `Key` and `Value` as headers, with the rows sorted
+    /// by value
+    ///
+    /// Entries with equal values are sorted by key, so the output is the same every time.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// # use std::collections::HashMap;
+    /// let mut map = HashMap::default();
+    ///
+    /// map.insert("Jon", 38);
+    /// map.insert("Jake", 25);
+    /// map.insert("Josh", 17);
+    ///
+    /// println!("{}", styles::THIN.format_hash_map_sorted_by_value(&map));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬─────┐
+    /// │ Key│Value│
+    /// ├────┼─────┤
+    /// │Josh│   17│
+    /// ├────┼─────┤
+    /// │Jake│   25│
+    /// ├────┼─────┤
+    /// │ Jon│   38│
+    /// └────┴─────┘
+    /// ```
+    #[cfg(feature = "std")]
+    fn format_hash_map_sorted_by_value(
+        &self,
+        map: &HashMap<impl Display + Ord, impl Display + Ord>,
+    ) -> String {
+        write_to_string(|w| self.write_hash_map_sorted_by_value(w, map))
+    }
+
+    /// Write a [`HashMap`] into `w` as a table using `Key` and `Value` as headers, with the rows
+    /// sorted by value
+    ///
+    /// The default implementation calls [`Self::write_pairs`] with the sorted entries of `map`.
+    ///
+    /// See [`Self::format_hash_map_sorted_by_value`] for example and more info
+    #[cfg(feature = "std")]
+    fn write_hash_map_sorted_by_value(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        map: &HashMap<impl Display + Ord, impl Display + Ord>,
+    ) -> fmt::Result {
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_by(|(a, x), (b, y)| x.cmp(y).then_with(|| a.cmp(b)));
+        self.write_pairs(w, entries)
+    }
+
+    /// Format a [`HashMap`] of [`HashMap`]s as a table using `Key` and `Value` as headers, with
+    /// each inner map drawn as a table nested inside its value cell
+    ///
+    /// Like [`Self::format_hash_map`], the rows are in the iteration order of the maps.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// # use std::collections::HashMap;
+    /// let mut map = HashMap::default();
+    /// map.insert("Springfield", HashMap::from([("Jon", 38), ("Jake", 25)]));
+    /// map.insert("Shelbyville", HashMap::from([("Finn", 14)]));
+    ///
+    /// println!("{}", styles::THIN.format_nested_hash_map(&map));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌───────────┬───────┐
+    /// │        Key│  Value│
+    /// ├───────────┼────┬──┤
+    /// │Springfield│ Jon│38│
+    /// │           ├────┼──┤
+    /// │           │Jake│25│
+    /// ├───────────┼────┼──┤
+    /// │Shelbyville│Finn│14│
+    /// └───────────┴────┴──┘
+    /// ```
+    #[cfg(feature = "std")]
+    fn format_nested_hash_map(
+        &self,
+        map: &HashMap<impl Display, HashMap<impl Display, impl Display>>,
+    ) -> String {
+        write_to_string(|w| self.write_nested_hash_map(w, map))
+    }
+
+    /// Write a [`HashMap`] of [`HashMap`]s into `w` as a table, with each inner map drawn as a
+    /// table nested inside its value cell
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_nested_hash_map`] for example and more info
+    #[cfg(feature = "std")]
+    fn write_nested_hash_map(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        map: &HashMap<impl Display, HashMap<impl Display, impl Display>>,
+    ) -> fmt::Result {
+        let mut table = TableBuilder::new();
+        table.header(["Key", "Value"]);
+        for (key, inner) in map {
+            let mut nested = TableBuilder::new();
+            nested.pairs(inner);
+            table.styled_row([Cell::new(key), Cell::table(nested)]);
+        }
+        self.write_table_builder(w, &table)
+    }
+
+    /// Format a [`HashMap`] of [`HashMap`]s as a table using `Group`, `Key` and `Value` as
+    /// headers, with a row for each entry of the inner maps and the outer key spanning the rows
+    /// of its group
+    ///
+    /// Like [`Self::format_hash_map`], the rows are in the iteration order of the maps.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// # use std::collections::HashMap;
+    /// let mut map = HashMap::default();
+    /// map.insert("Springfield", HashMap::from([("Jon", 38), ("Jake", 25)]));
+    /// map.insert("Shelbyville", HashMap::from([("Finn", 14)]));
+    ///
+    /// println!("{}", styles::THIN.format_nested_hash_map_grouped(&map));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌───────────┬────┬─────┐
+    /// │      Group│ Key│Value│
+    /// ├───────────┼────┼─────┤
+    /// │Springfield│ Jon│   38│
+    /// │           ├────┼─────┤
+    /// │           │Jake│   25│
+    /// ├───────────┼────┼─────┤
+    /// │Shelbyville│Finn│   14│
+    /// └───────────┴────┴─────┘
+    /// ```
+    #[cfg(feature = "std")]
+    fn format_nested_hash_map_grouped(
+        &self,
+        map: &HashMap<impl Display, HashMap<impl Display, impl Display>>,
+    ) -> String {
+        write_to_string(|w| self.write_nested_hash_map_grouped(w, map))
+    }
+
+    /// Write a [`HashMap`] of [`HashMap`]s into `w` as a table with a row for each entry of the
+    /// inner maps, grouped under the outer key
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_nested_hash_map_grouped`] for example and more info
+    #[cfg(feature = "std")]
+    fn write_nested_hash_map_grouped(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        map: &HashMap<impl Display, HashMap<impl Display, impl Display>>,
+    ) -> fmt::Result {
+        let mut table = TableBuilder::new();
+        table.header(["Group", "Key", "Value"]);
+        for (group, inner) in map {
+            // An empty group still gets a row of its own
+            let mut group = Some(Cell::new(group).with_rowspan(inner.len().max(1)));
+            if inner.is_empty() {
+                table.styled_row(group.take());
+            }
+            for (key, value) in inner {
+                let row = group.take().into_iter();
+                table.styled_row(row.chain([Cell::new(key), Cell::new(value)]));
+            }
+        }
+        self.write_table_builder(w, &table)
+    }
+
+    /// Format key/value pairs in which a key may appear more than once as a table using `Key` and
+    /// `Value` as headers, with a row for each value and each key spanning the rows of its values
+    ///
+    /// The keys are grouped in the order they first appear, and the values of each key keep the
+    /// order they are given in, so this can show data that a [`HashMap`] can't hold.  See
+    /// [`Self::format_multimap_joined`] to put all of a key's values in one cell.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let pets = [("Jon", "Garfield"), ("Jake", "Rex"), ("Jon", "Odie")];
+    ///
+    /// println!("{}", styles::THIN.format_multimap(pets));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬────────┐
+    /// │ Key│   Value│
+    /// ├────┼────────┤
+    /// │ Jon│Garfield│
+    /// │    ├────────┤
+    /// │    │    Odie│
+    /// ├────┼────────┤
+    /// │Jake│     Rex│
+    /// └────┴────────┘
+    /// ```
+    fn format_multimap(
+        &self,
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+    ) -> String {
+        write_to_string(|w| self.write_multimap(w, pairs))
+    }
+
+    /// Write key/value pairs in which a key may appear more than once into `w` as a table, with a
+    /// row for each value grouped under its key
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_multimap`] for example and more info
+    fn write_multimap(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+    ) -> fmt::Result {
+        let mut table = TableBuilder::new();
+        table.header(["Key", "Value"]);
+        for (key, values) in group_pairs(pairs) {
+            let mut key = Some(Cell::new(key).with_rowspan(values.len()));
+            for value in values {
+                let row = key.take().into_iter();
+                table.styled_row(row.chain([Cell::new(value)]));
+            }
+        }
+        self.write_table_builder(w, &table)
+    }
+
+    /// Format key/value pairs in which a key may appear more than once as a table using `Key` and
+    /// `Value` as headers, with a row for each key and its values joined by `separator`
+    ///
+    /// Like [`Self::format_multimap`], the keys are in the order they first appear.  A separator
+    /// containing a newline puts each value on a line of its own within the cell.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let pets = [("Jon", "Garfield"), ("Jake", "Rex"), ("Jon", "Odie")];
+    ///
+    /// println!("{}", styles::THIN.format_multimap_joined(pets, ", "));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬──────────────┐
+    /// │ Key│         Value│
+    /// ├────┼──────────────┤
+    /// │ Jon│Garfield, Odie│
+    /// ├────┼──────────────┤
+    /// │Jake│           Rex│
+    /// └────┴──────────────┘
+    /// ```
+    fn format_multimap_joined(
+        &self,
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+        separator: &str,
+    ) -> String {
+        write_to_string(|w| self.write_multimap_joined(w, pairs, separator))
+    }
+
+    /// Write key/value pairs in which a key may appear more than once into `w` as a table, with a
+    /// row for each key and its values joined by `separator`
+    ///
+    /// The default implementation joins the values of each key and passes them to
+    /// [`Self::write_pairs`].
+    ///
+    /// See [`Self::format_multimap_joined`] for example and more info
+    fn write_multimap_joined(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+        separator: &str,
+    ) -> fmt::Result {
+        self.write_pairs(
+            w,
+            group_pairs(pairs)
+                .into_iter()
+                .map(|(key, values)| (key, values.join(separator))),
+        )
+    }
+
+    /// Format key/value pairs as a table using `Key` and `Value` as headers
+    ///
+    /// Unlike [`Self::format_hash_map`], this accepts anything that can be iterated as pairs, such
+    /// as a [`BTreeMap`] or a [`Vec`] of tuples, and keeps the rows in the order they are given.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// # use std::collections::BTreeMap;
+    /// let mut map = BTreeMap::default();
+    ///
+    /// map.insert("Jon", 38);
+    /// map.insert("Jake", 25);
+    /// map.insert("Josh", 17);
+    ///
+    /// println!("{}", styles::THIN.format_pairs(&map));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬─────┐
+    /// │ Key│Value│
+    /// ├────┼─────┤
+    /// │Jake│   25│
+    /// ├────┼─────┤
+    /// │ Jon│   38│
+    /// ├────┼─────┤
+    /// │Josh│   17│
+    /// └────┴─────┘
+    /// ```
+    fn format_pairs(
+        &self,
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+    ) -> String {
+        write_to_string(|w| self.write_pairs(w, pairs))
+    }
+
+    /// Write key/value pairs into `w` as a table using `Key` and `Value` as headers
+    ///
+    /// The default implementation calls [`Self::write_pairs_headers`] with `"Key"` as the key
+    /// header and `"Value"` as the value header.
+    ///
+    /// See [`Self::format_pairs`] for example and more info
+    fn write_pairs(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+    ) -> fmt::Result {
+        self.write_pairs_headers(w, pairs, "Key", "Value")
+    }
+
+    /// Format key/value pairs as a table using given headers
+    ///
+    /// If the headers are both empty, no header should be applied.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let scores = vec![("Jon", 38), ("Jake", 25), ("Josh", 17)];
+    ///
+    /// println!(
+    ///     "{}",
+    ///     styles::THIN.format_pairs_headers(scores, "Name", "Score")
+    /// );
+    /// ```
+    /// produces the output:
+    /// ```text
+    /// ┌────┬─────┐
+    /// │Name│Score│
+    /// ├────┼─────┤
+    /// │ Jon│   38│
+    /// ├────┼─────┤
+    /// │Jake│   25│
+    /// ├────┼─────┤
+    /// │Josh│   17│
+    /// └────┴─────┘
+    /// ```
+    fn format_pairs_headers(
+        &self,
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+        key_header: impl AsRef<str>,
+        value_header: impl AsRef<str>,
+    ) -> String {
+        write_to_string(|w| self.write_pairs_headers(w, pairs, key_header, value_header))
+    }
+
+    /// Write key/value pairs into `w` as a table using given headers
+    ///
+    /// The default implementation builds a [`TableBuilder`] with a row for each pair and passes
+    /// it to [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_pairs_headers`] for example and more info
+    fn write_pairs_headers(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+        key_header: impl AsRef<str>,
+        value_header: impl AsRef<str>,
+    ) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            TableBuilder::new()
+                .header([key_header.as_ref(), value_header.as_ref()])
+                .pairs(pairs),
+        )
+    }
+
+    /// Format key/value pairs as a table with two rows, with the keys in the header and each
+    /// value below its key
+    ///
+    /// Every column is centered, which suits a strip of labelled values such as a status line.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let status = [("Branch", "main"), ("Ahead", "2"), ("Modified", "5")];
+    /// println!("{}", styles::ROUNDED.format_pairs_horizontal(status));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ╭──────┬─────┬────────╮
+    /// │Branch│Ahead│Modified│
+    /// ├──────┼─────┼────────┤
+    /// │ main │  2  │   5    │
+    /// ╰──────┴─────┴────────╯
+    /// ```
+    fn format_pairs_horizontal(
+        &self,
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+    ) -> String {
+        write_to_string(|w| self.write_pairs_horizontal(w, pairs))
+    }
+
+    /// Write key/value pairs into `w` as a table with two rows, with the keys in the header and
+    /// each value below its key
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_pairs_horizontal`] for example and more info
+    fn write_pairs_horizontal(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+    ) -> fmt::Result {
+        let (keys, values): (Vec<_>, Vec<_>) = pairs
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .unzip();
+        self.write_table_builder(
+            w,
+            TableBuilder::new()
+                .header(&keys)
+                .row(values)
+                .alignments(&vec![Alignment::Center; keys.len()]),
+        )
+    }
+
+    /// Format the fields of a single record as a card, with each field's name and value on a row
+    /// of their own
+    ///
+    /// Both the names and the values are left-aligned and there are no rules between the rows,
+    /// which suits showing the details of one record rather than comparing many of them.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let card = styles::THIN.format_card([
+    ///     ("Name", "borderrs"),
+    ///     ("Version", "0.1.1"),
+    ///     ("License", "Apache-2.0"),
+    /// ]);
+    /// println!("{}", card);
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌───────┬──────────┐
+    /// │Name   │borderrs  │
+    /// │Version│0.1.1     │
+    /// │License│Apache-2.0│
+    /// └───────┴──────────┘
+    /// ```
+    fn format_card(&self, pairs: impl IntoIterator<Item = (impl Display, impl Display)>) -> String {
+        write_to_string(|w| self.write_card(w, pairs))
+    }
+
+    /// Write the fields of a single record into `w` as a card, with each field's name and value
+    /// on a row of their own
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_card`] for example and more info
+    fn write_card(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+    ) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            TableBuilder::new()
+                .pairs(pairs)
+                .alignments(&[Alignment::Left, Alignment::Left])
+                .row_separator(RowSeparator::None),
+        )
+    }
+
+    /// Format labelled values as a bar chart, with a row for each label that has a bar `width`
+    /// characters long followed by the value
+    ///
+    /// The largest value fills its whole bar and the others are drawn in proportion to it, see
+    /// [`Cell::bar`] for how the bars are drawn.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let downloads = [("Mon", 12.0), ("Tue", 30.0), ("Wed", 21.0)];
+    /// println!("{}", styles::THIN.format_bars(downloads, 10));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌───┬──────────┬──┐
+    /// │Mon│████░░░░░░│12│
+    /// ├───┼──────────┼──┤
+    /// │Tue│██████████│30│
+    /// ├───┼──────────┼──┤
+    /// │Wed│███████░░░│21│
+    /// └───┴──────────┴──┘
+    /// ```
+    fn format_bars(
+        &self,
+        bars: impl IntoIterator<Item = (impl Display, f64)>,
+        width: usize,
+    ) -> String {
+        write_to_string(|w| self.write_bars(w, bars, width))
+    }
+
+    /// Write labelled values into `w` as a bar chart, with a row for each label that has a bar
+    /// `width` characters long followed by the value
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_bars`] for example and more info
+    fn write_bars(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        bars: impl IntoIterator<Item = (impl Display, f64)>,
+        width: usize,
+    ) -> fmt::Result {
+        let bars: Vec<_> = bars.into_iter().collect();
+        let max = bars.iter().map(|&(_, value)| value).fold(0.0, f64::max);
+
+        let mut table = TableBuilder::new();
+        for (label, value) in &bars {
+            table.styled_row([
+                Cell::new(label),
+                Cell::bar(*value, max, width),
+                Cell::new(value),
+            ]);
+        }
+        self.write_table_builder(w, table.alignments(&[Alignment::Left, Alignment::Left]))
+    }
+
+    /// Format numbers as a sparkline, with a bar for each number inside of a border
+    ///
+    /// The bars are scaled so that the smallest number is drawn with `▁` and the largest with
+    /// `█`.  Numbers that are infinite or `NaN` are drawn as a space.  Without any numbers, the
+    /// placeholder of an empty table is drawn instead (see [`table::EmptyBehavior`]).
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let latency = [12, 15, 13, 40, 22, 18, 12, 50];
+    /// println!("{}", styles::THIN.format_sparkline(&latency));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌────────┐
+    /// │▁▂▁▆▃▂▁█│
+    /// └────────┘
+    /// ```
+    /// and without any numbers,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// assert_eq!(
+    ///     styles::THIN.format_sparkline(&[] as &[f64]),
+    ///     "┌─────────┐\n│(no data)│\n└─────────┘",
+    /// );
+    /// ```
+    fn format_sparkline(&self, values: &[impl Into<f64> + Copy]) -> String {
+        write_to_string(|w| self.write_sparkline(w, values))
+    }
+
+    /// Write numbers into `w` as a sparkline, with a bar for each number inside of a border
+    ///
+    /// The default implementation calls [`Self::write_display`] with the sparkline.
+    ///
+    /// See [`Self::format_sparkline`] for example and more info
+    fn write_sparkline(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        values: &[impl Into<f64> + Copy],
+    ) -> fmt::Result {
+        if values.is_empty() {
+            return self.write_table_builder(w, &TableBuilder::new());
+        }
+        let values: Vec<f64> = values.iter().map(|&value| value.into()).collect();
+        self.write_display(w, chart::sparkline(&values))
+    }
+
+    /// Format a histogram of `values`, sorting them into `buckets` ranges of the same size and
+    /// drawing a bar for each range along with how many values are in it
+    ///
+    /// The ranges cover everything from the smallest value to the largest, and values that are
+    /// infinite or `NaN` are left out.  A `buckets` of zero is treated as one.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let ages = [0, 14, 15, 22, 25, 27, 29, 31, 38, 40];
+    /// println!("{}", styles::THIN.format_histogram(ages, 4));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌────────┬──────────────────────┐
+    /// │Range   │Count                 │
+    /// ├────────┼──────────────────────┤
+    /// │[0, 10) │█████░░░░░░░░░░░░░░░ 1│
+    /// ├────────┼──────────────────────┤
+    /// │[10, 20)│██████████░░░░░░░░░░ 2│
+    /// ├────────┼──────────────────────┤
+    /// │[20, 30)│████████████████████ 4│
+    /// ├────────┼──────────────────────┤
+    /// │[30, 40]│███████████████░░░░░ 3│
+    /// └────────┴──────────────────────┘
+    /// ```
+    fn format_histogram(
+        &self,
+        values: impl IntoIterator<Item = impl Into<f64>>,
+        buckets: usize,
+    ) -> String {
+        write_to_string(|w| self.write_histogram(w, values, buckets))
+    }
+
+    /// Write a histogram of `values` into `w`, sorting them into `buckets` ranges of the same size
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_histogram`] for example and more info
+    fn write_histogram(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        values: impl IntoIterator<Item = impl Into<f64>>,
+        buckets: usize,
+    ) -> fmt::Result {
+        let values: Vec<f64> = values.into_iter().map(Into::into).collect();
+        let buckets = chart::histogram(&values, buckets);
+        let max = buckets.iter().map(|&(_, count)| count).max().unwrap_or(0);
+
+        let mut table = TableBuilder::new();
+        table.header(["Range", "Count"]);
+        for (range, count) in buckets {
+            let bar = chart::bar(count as f64, max as f64, chart::HISTOGRAM_WIDTH);
+            table.row([range, format!("{} {}", bar, count)]);
+        }
+        self.write_table_builder(w, table.alignments(&[Alignment::Left, Alignment::Left]))
+    }
+
+    /// Format key/value pairs as a table where each value (such as a tuple or a struct) is spread
+    /// across several columns by `split`
+    ///
+    /// Like [`Self::format_pairs`], this accepts a [`HashMap`], a [`BTreeMap`], or anything else
+    /// that can be iterated as pairs.  The first of the `headers` is used for the keys and the rest
+    /// for the columns returned by `split`.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// # use std::collections::BTreeMap;
+    /// let mut people = BTreeMap::default();
+    ///
+    /// people.insert(1, ("Jon", 38, "Springfield"));
+    /// people.insert(2, ("Jake", 25, "Shelbyville"));
+    ///
+    /// println!(
+    ///     "{}",
+    ///     styles::THIN.format_map_columns(&people, &["Id", "Name", "Age", "Town"], |person| {
+    ///         [person.0.to_string(), person.1.to_string(), person.2.to_string()]
+    ///     })
+    /// );
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌──┬────┬───┬───────────┐
+    /// │Id│Name│Age│       Town│
+    /// ├──┼────┼───┼───────────┤
+    /// │ 1│ Jon│ 38│Springfield│
+    /// ├──┼────┼───┼───────────┤
+    /// │ 2│Jake│ 25│Shelbyville│
+    /// └──┴────┴───┴───────────┘
+    /// ```
+    fn format_map_columns<V, C: IntoIterator<Item = impl Display>>(
+        &self,
+        pairs: impl IntoIterator<Item = (impl Display, V)>,
+        headers: &[impl AsRef<str>],
+        split: impl FnMut(V) -> C,
+    ) -> String {
+        write_to_string(|w| self.write_map_columns(w, pairs, headers, split))
+    }
+
+    /// Write key/value pairs into `w` as a table where each value is spread across several
+    /// columns by `split`
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_map_columns`] for example and more info
+    fn write_map_columns<V, C: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        pairs: impl IntoIterator<Item = (impl Display, V)>,
+        headers: &[impl AsRef<str>],
+        mut split: impl FnMut(V) -> C,
+    ) -> fmt::Result {
+        let rows = pairs.into_iter().map(|(key, value)| {
+            iter::once(key.to_string())
+                .chain(split(value).into_iter().map(|column| column.to_string()))
+                .collect::<Vec<_>>()
+        });
+        let headers = headers.iter().map(AsRef::as_ref);
+        self.write_table_builder(w, TableBuilder::new().header(headers).rows(rows))
+    }
+
+    /// Format a 2D table where each inner [`Vec`] is a row of cells
+    ///
+    /// Each column is as wide as its widest cell and each row is as tall as its tallest cell.
+    /// Rows that are shorter than the longest row are padded with empty cells.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let table = vec![
+    ///     vec!["Name", "Age", "City"],
+    ///     vec!["Jon", "38", "Chicago"],
+    ///     vec!["Jake", "25"],
+    /// ];
+    ///
+    /// println!("{}", styles::THIN.format_table(&table));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┬───────┐
+    /// │Name│Age│   City│
+    /// ├────┼───┼───────┤
+    /// │ Jon│ 38│Chicago│
+    /// ├────┼───┼───────┤
+    /// │Jake│ 25│       │
+    /// └────┴───┴───────┘
+    /// ```
+    fn format_table(&self, rows: &[Vec<impl Display>]) -> String {
+        write_to_string(|w| self.write_table(w, rows))
+    }
+
+    /// Write a 2D table into `w` where each inner [`Vec`] is a row of cells
+    ///
+    /// The default implementation calls [`Self::write_table_with_headers`] without any headers.
+    ///
+    /// See [`Self::format_table`] for example and more info
+    fn write_table(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        rows: &[Vec<impl Display>],
+    ) -> fmt::Result {
+        self.write_table_with_headers(w, &[] as &[&str], rows)
+    }
+
+    /// Format a 2D table of values that may be missing, such as [`Option`]s or [`Result`]s,
+    /// drawing `placeholder` in place of each missing value
+    ///
+    /// See [`Self::format_table`] for more info about how the rows are formatted.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let rows = vec![vec![Some(1), None], vec![None, Some(4)]];
+    /// println!("{}", styles::THIN.format_table_opt(&rows, "—"));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌─┬─┐
+    /// │1│—│
+    /// ├─┼─┤
+    /// │—│4│
+    /// └─┴─┘
+    /// ```
+    fn format_table_opt(
+        &self,
+        rows: &[Vec<impl OptionalCell>],
+        placeholder: impl Display,
+    ) -> String {
+        write_to_string(|w| self.write_table_opt(w, rows, placeholder))
+    }
+
+    /// Write a 2D table of values that may be missing into `w`, drawing `placeholder` in place
+    /// of each missing value
+    ///
+    /// The default implementation calls [`Self::write_table`] with the placeholder filled in.
+    ///
+    /// See [`Self::format_table_opt`] for example and more info
+    fn write_table_opt(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        rows: &[Vec<impl OptionalCell>],
+        placeholder: impl Display,
+    ) -> fmt::Result {
+        let placeholder = placeholder.to_string();
+        let rows: Vec<Vec<_>> = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| or_placeholder(cell, &placeholder))
+                    .collect()
+            })
+            .collect();
+        self.write_table(w, &rows)
+    }
+
+    /// Format a 2D table with a header row on top
+    ///
+    /// If the headers are all empty, no header should be applied.
+    ///
+    /// See [`Self::format_table`] for more info about how the rows are formatted.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let rows = vec![
+    ///     vec!["Jon", "38", "Chicago"],
+    ///     vec!["Jake", "25", "Denver"],
+    /// ];
+    ///
+    /// println!(
+    ///     "{}",
+    ///     styles::DOUBLE.format_table_with_headers(&["Name", "Age", "City"], &rows)
+    /// );
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ╔════╦═══╦═══════╗
+    /// ║Name║Age║   City║
+    /// ╠════╬═══╬═══════╣
+    /// ║ Jon║ 38║Chicago║
+    /// ╠════╬═══╬═══════╣
+    /// ║Jake║ 25║ Denver║
+    /// ╚════╩═══╩═══════╝
+    /// ```
+    fn format_table_with_headers(
+        &self,
+        headers: &[impl AsRef<str>],
+        rows: &[Vec<impl Display>],
+    ) -> String {
+        write_to_string(|w| self.write_table_with_headers(w, headers, rows))
+    }
+
+    /// Write a 2D table into `w` with a header row on top
+    ///
+    /// The default implementation calls [`Self::write_table_aligned`] with every column using
+    /// [`Alignment::Right`].
+    ///
+    /// See [`Self::format_table_with_headers`] for example and more info
+    fn write_table_with_headers(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        headers: &[impl AsRef<str>],
+        rows: &[Vec<impl Display>],
+    ) -> fmt::Result {
+        self.write_table_aligned(w, headers, rows, &[])
+    }
+
+    /// Format a 2D table with a header row on top, using a separate [`Alignment`] for each column
+    ///
+    /// Columns that don't have an entry in `alignments` use [`Alignment::Right`].
+    ///
+    /// See [`Self::format_table_with_headers`] for more info.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, Alignment, BorderFormatter};
+    /// let rows = vec![
+    ///     vec!["Jon", "38", "Chicago"],
+    ///     vec!["Jake", "25", "Denver"],
+    /// ];
+    ///
+    /// println!(
+    ///     "{}",
+    ///     styles::THIN.format_table_aligned(
+    ///         &["Name", "Age", "City"],
+    ///         &rows,
+    ///         &[Alignment::Left, Alignment::Right, Alignment::Center]
+    ///     )
+    /// );
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┬───────┐
+    /// │Name│Age│ City  │
+    /// ├────┼───┼───────┤
+    /// │Jon │ 38│Chicago│
+    /// ├────┼───┼───────┤
+    /// │Jake│ 25│Denver │
+    /// └────┴───┴───────┘
+    /// ```
+    fn format_table_aligned(
+        &self,
+        headers: &[impl AsRef<str>],
+        rows: &[Vec<impl Display>],
+        alignments: &[Alignment],
+    ) -> String {
+        write_to_string(|w| self.write_table_aligned(w, headers, rows, alignments))
+    }
+
+    /// Write a 2D table into `w` with a header row on top, using a separate [`Alignment`] for
+    /// each column
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_table_aligned`] for example and more info
+    fn write_table_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        headers: &[impl AsRef<str>],
+        rows: &[Vec<impl Display>],
+        alignments: &[Alignment],
+    ) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            TableBuilder::new()
+                .header(headers.iter().map(AsRef::as_ref))
+                .rows(rows)
+                .alignments(alignments),
+        )
+    }
+
+    /// Format a 2D table with a header row on top, aligning the header using `header_alignment`
+    /// and each column of the body using a separate [`Alignment`]
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, Alignment, BorderFormatter};
+    /// let rows = vec![vec!["Jonathan", "38"], vec!["Jake", "125"]];
+    /// println!(
+    ///     "{}",
+    ///     styles::THIN.format_table_header_aligned(
+    ///         &["Name", "Age"],
+    ///         &rows,
+    ///         Alignment::Center,
+    ///         &[Alignment::Left, Alignment::Right]
+    ///     )
+    /// );
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────┬───┐
+    /// │  Name  │Age│
+    /// ├────────┼───┤
+    /// │Jonathan│ 38│
+    /// ├────────┼───┤
+    /// │Jake    │125│
+    /// └────────┴───┘
+    /// ```
+    fn format_table_header_aligned(
+        &self,
+        headers: &[impl AsRef<str>],
+        rows: &[Vec<impl Display>],
+        header_alignment: Alignment,
+        alignments: &[Alignment],
+    ) -> String {
+        write_to_string(|w| {
+            self.write_table_header_aligned(w, headers, rows, header_alignment, alignments)
+        })
+    }
+
+    /// Write a 2D table into `w` with a header row on top, aligning the header using
+    /// `header_alignment` and each column of the body using a separate [`Alignment`]
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_table_header_aligned`] for example and more info
+    fn write_table_header_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        headers: &[impl AsRef<str>],
+        rows: &[Vec<impl Display>],
+        header_alignment: Alignment,
+        alignments: &[Alignment],
+    ) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            TableBuilder::new()
+                .header(headers.iter().map(AsRef::as_ref))
+                .rows(rows)
+                .alignments(alignments)
+                .header_alignment(header_alignment),
+        )
+    }
+
+    /// Format a 2D table, using `style` to turn each value into a [`Cell`]
+    ///
+    /// `style` is called with the index of the row, the index of the column, and the value, so
+    /// it can be used to highlight specific cells.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{color::Color, styles, table::Cell, BorderFormatter};
+    /// let table = vec![vec![12, -3], vec![-7, 40]];
+    /// let output = styles::THIN.format_table_with(&table, |_row, _col, &value| {
+    ///     let cell = Cell::new(value);
+    ///     if value < 0 {
+    ///         cell.with_color(Color::Red)
+    ///     } else {
+    ///         cell
+    ///     }
+    /// });
+    /// println!("{}", output);
+    /// ```
+    /// produces the output (with `-3` and `-7` in red)
+    /// ```text
+    /// ┌──┬──┐
+    /// │12│-3│
+    /// ├──┼──┤
+    /// │-7│40│
+    /// └──┴──┘
+    /// ```
+    fn format_table_with<T>(
+        &self,
+        rows: &[Vec<T>],
+        style: impl FnMut(usize, usize, &T) -> Cell,
+    ) -> String {
+        write_to_string(|w| self.write_table_with(w, rows, style))
+    }
+
+    /// Write a 2D table into `w`, using `style` to turn each value into a [`Cell`]
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_table_with`] for example and more info
+    fn write_table_with<T>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        rows: &[Vec<T>],
+        mut style: impl FnMut(usize, usize, &T) -> Cell,
+    ) -> fmt::Result {
+        let mut table = TableBuilder::new();
+        for (i, row) in rows.iter().enumerate() {
+            table.styled_row(row.iter().enumerate().map(|(j, value)| style(i, j, value)));
+        }
+        self.write_table_builder(w, &table)
+    }
+
+    /// Format a matrix (a slice of rows, such as `&[[T; N]]` or `&[&[T]]`) as a grid
+    ///
+    /// Each column is as wide as its widest cell, use [`Self::format_matrix_uniform`] to make
+    /// every column the same width.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let matrix = [[1, 0, 0], [0, 10, 0], [0, 0, 100]];
+    /// println!("{}", styles::THIN.format_matrix(&matrix));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌─┬──┬───┐
+    /// │1│ 0│  0│
+    /// ├─┼──┼───┤
+    /// │0│10│  0│
+    /// ├─┼──┼───┤
+    /// │0│ 0│100│
+    /// └─┴──┴───┘
+    /// ```
+    fn format_matrix<T: Display>(&self, matrix: &[impl AsRef<[T]>]) -> String {
+        write_to_string(|w| self.write_matrix(w, matrix))
+    }
+
+    /// Write a matrix (a slice of rows, such as `&[[T; N]]` or `&[&[T]]`) into `w` as a grid
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_matrix`] for example and more info
+    fn write_matrix<T: Display>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        matrix: &[impl AsRef<[T]>],
+    ) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            TableBuilder::new().rows(matrix.iter().map(AsRef::as_ref)),
+        )
+    }
+
+    /// Format a matrix (a slice of rows, such as `&[[T; N]]` or `&[&[T]]`) as a grid where
+    /// every column has the same width
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let matrix = [[1, 0, 0], [0, 10, 0], [0, 0, 100]];
+    /// println!("{}", styles::THIN.format_matrix_uniform(&matrix));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌───┬───┬───┐
+    /// │  1│  0│  0│
+    /// ├───┼───┼───┤
+    /// │  0│ 10│  0│
+    /// ├───┼───┼───┤
+    /// │  0│  0│100│
+    /// └───┴───┴───┘
+    /// ```
+    fn format_matrix_uniform<T: Display>(&self, matrix: &[impl AsRef<[T]>]) -> String {
+        write_to_string(|w| self.write_matrix_uniform(w, matrix))
+    }
+
+    /// Write a matrix (a slice of rows, such as `&[[T; N]]` or `&[&[T]]`) into `w` as a grid
+    /// where every column has the same width
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_matrix_uniform`] for example and more info
+    fn write_matrix_uniform<T: Display>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        matrix: &[impl AsRef<[T]>],
+    ) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            TableBuilder::new()
+                .rows(matrix.iter().map(AsRef::as_ref))
+                .uniform_width(true),
+        )
+    }
+
+    /// Format a month of the calendar as a grid with a column for each day of the week, starting
+    /// on Monday, and the month and year as its title
+    ///
+    /// `month` goes from 1 for January to 12 for December.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` isn't between 1 and 12.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// println!("{}", styles::THIN.format_month(2024, 2));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌─ February 2024 ─┬──┐
+    /// │Mo│Tu│We│Th│Fr│Sa│Su│
+    /// ├──┼──┼──┼──┼──┼──┼──┤
+    /// │  │  │  │ 1│ 2│ 3│ 4│
+    /// ├──┼──┼──┼──┼──┼──┼──┤
+    /// │ 5│ 6│ 7│ 8│ 9│10│11│
+    /// ├──┼──┼──┼──┼──┼──┼──┤
+    /// │12│13│14│15│16│17│18│
+    /// ├──┼──┼──┼──┼──┼──┼──┤
+    /// │19│20│21│22│23│24│25│
+    /// ├──┼──┼──┼──┼──┼──┼──┤
+    /// │26│27│28│29│  │  │  │
+    /// └──┴──┴──┴──┴──┴──┴──┘
+    /// ```
+    fn format_month(&self, year: i32, month: u32) -> String {
+        write_to_string(|w| self.write_month(w, year, month))
+    }
+
+    /// Write a month of the calendar into `w` as a grid with a column for each day of the week
+    ///
+    /// The default implementation calls [`Self::write_month_highlighted`] without highlighting
+    /// any days.
+    ///
+    /// See [`Self::format_month`] for example and more info
+    fn write_month(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        year: i32,
+        month: u32,
+    ) -> fmt::Result {
+        self.write_month_highlighted(w, year, month, |_| None)
+    }
+
+    /// Format a month of the calendar like [`Self::format_month`], drawing each day in the color
+    /// returned by `highlight` (if there is one)
+    ///
+    /// `highlight` is called with the day of the month, starting from 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` isn't between 1 and 12.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{color::Color, styles, BorderFormatter};
+    /// // Highlight the weekends
+    /// let calendar = styles::THIN.format_month_highlighted(2024, 2, |day| {
+    ///     matches!(day % 7, 3 | 4).then_some(Color::Red)
+    /// });
+    /// println!("{}", calendar);
+    /// ```
+    fn format_month_highlighted(
+        &self,
+        year: i32,
+        month: u32,
+        highlight: impl Fn(u32) -> Option<Color>,
+    ) -> String {
+        write_to_string(|w| self.write_month_highlighted(w, year, month, highlight))
+    }
+
+    /// Write a month of the calendar into `w`, drawing each day in the color returned by
+    /// `highlight` (if there is one)
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_month_highlighted`] for example and more info
+    fn write_month_highlighted(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        year: i32,
+        month: u32,
+        highlight: impl Fn(u32) -> Option<Color>,
+    ) -> fmt::Result {
+        assert!((1..=12).contains(&month), "month must be between 1 and 12");
+
+        let mut table = TableBuilder::new();
+        table
+            .title(format!("{} {}", calendar::MONTHS[month as usize - 1], year))
+            .header(calendar::WEEKDAYS)
+            .uniform_width(true);
+
+        // Leave the days before the first of the month empty
+        let blanks = iter::repeat_n(None, calendar::first_weekday(year, month));
+        let days = (1..=calendar::days_in_month(year, month)).map(Some);
+        let days: Vec<_> = blanks.chain(days).collect();
+        for week in days.chunks(calendar::WEEKDAYS.len()) {
+            table.styled_row(week.iter().map(|&day| match day {
+                Some(day) => match highlight(day) {
+                    Some(color) => Cell::new(day).with_color(color),
+                    None => Cell::new(day),
+                },
+                None => Cell::default(),
+            }));
+        }
+        self.write_table_builder(w, &table)
+    }
+
+    /// Format a tree, drawing each node on its own line with guides connecting it to its parent
+    ///
+    /// The guides are drawn with the same pieces as the style's borders, so every style produces
+    /// a tree that matches its tables.  Markdown styles write a nested list and HTML styles write
+    /// nested `<ul>` elements instead.
+    ///
+    /// See [`tree`] for how to implement [`TreeNode`], or use [`Self::format_tree_with`] to draw
+    /// a tree without implementing it.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, tree::TreeNode, BorderFormatter};
+    /// struct Task(&'static str, Vec<Task>);
+    ///
+    /// impl TreeNode for Task {
+    ///     fn label(&self) -> String {
+    ///         self.0.to_string()
+    ///     }
+    ///
+    ///     fn children(&self) -> Vec<&Self> {
+    ///         self.1.iter().collect()
+    ///     }
+    /// }
+    ///
+    /// let release = Task(
+    ///     "release",
+    ///     vec![
+    ///         Task("build", vec![Task("compile", vec![]), Task("link", vec![])]),
+    ///         Task("test", vec![]),
+    ///     ],
+    /// );
+    /// println!("{}", styles::THIN.format_tree(&release));
+    /// ```
+    /// produces
+    /// ```text
+    /// release
+    /// ├── build
+    /// │   ├── compile
+    /// │   └── link
+    /// └── test
+    /// ```
+    fn format_tree(&self, root: &impl TreeNode) -> String {
+        write_to_string(|w| self.write_tree(w, root))
+    }
+
+    /// Write a tree into `w`, drawing each node on its own line with guides connecting it to its
+    /// parent
+    ///
+    /// The default implementation calls [`Self::write_tree_with`] using [`TreeNode::label`] and
+    /// [`TreeNode::children`].
+    ///
+    /// See [`Self::format_tree`] for example and more info
+    fn write_tree(&self, w: &mut (impl fmt::Write + ?Sized), root: &impl TreeNode) -> fmt::Result {
+        self.write_tree_with(w, root, TreeNode::label, TreeNode::children)
+    }
+
+    /// Format a tree using `label` to get the text of each node and `children` to get the nodes
+    /// below it
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// // The tree of divisors, down to the primes
+    /// let divisors = |&n: &u32| -> Vec<u32> { (2..n).filter(|d| n % d == 0).collect() };
+    /// let nodes: Vec<u32> = (0..=12).collect();
+    /// println!(
+    ///     "{}",
+    ///     styles::ASCII.format_tree_with(&nodes[12], |n| n, |n| {
+    ///         divisors(n).into_iter().map(|d| &nodes[d as usize])
+    ///     })
+    /// );
+    /// ```
+    /// produces
+    /// ```text
+    /// 12
+    /// +-- 2
+    /// +-- 3
+    /// +-- 4
+    /// |   +-- 2
+    /// +-- 6
+    ///     +-- 2
+    ///     +-- 3
+    /// ```
+    fn format_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> String {
+        write_to_string(|w| self.write_tree_with(w, root, label, children))
+    }
+
+    /// Write a tree into `w`, using `label` to get the text of each node and `children` to get the
+    /// nodes below it
+    ///
+    /// The default implementation draws the guides with the thin box-drawing characters.
+    ///
+    /// See [`Self::format_tree_with`] for example and more info
+    fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> fmt::Result {
+        let guides = Guides::new("├── ", "└── ", "│   ", "    ");
+        tree::write_tree(w, root, &label, &children, &guides)
+    }
+
+    /// Format a matrix (a slice of rows, such as `&[[T; N]]` or `&[&[T]]`) as a grid with a label
+    /// for each row and column
+    ///
+    /// The row labels go in a left-aligned column before the data, and the column labels go in a
+    /// header above it, with the top-left corner left blank.  Rows without a label get an empty
+    /// one, and the header is left out if there are no column labels.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let matrix = [[1, 2], [3, 4], [5, 6]];
+    /// println!(
+    ///     "{}",
+    ///     styles::THIN.format_matrix_labeled(&["one", "two", "three"], &["x", "y"], &matrix)
+    /// );
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌─────┬─┬─┐
+    /// │     │x│y│
+    /// ├─────┼─┼─┤
+    /// │one  │1│2│
+    /// ├─────┼─┼─┤
+    /// │two  │3│4│
+    /// ├─────┼─┼─┤
+    /// │three│5│6│
+    /// └─────┴─┴─┘
+    /// ```
+    fn format_matrix_labeled<T: Display>(
+        &self,
+        row_labels: &[impl Display],
+        column_labels: &[impl Display],
+        matrix: &[impl AsRef<[T]>],
+    ) -> String {
+        write_to_string(|w| self.write_matrix_labeled(w, row_labels, column_labels, matrix))
+    }
+
+    /// Write a matrix (a slice of rows, such as `&[[T; N]]` or `&[&[T]]`) into `w` as a grid with
+    /// a label for each row and column
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_matrix_labeled`] for example and more info
+    fn write_matrix_labeled<T: Display>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        row_labels: &[impl Display],
+        column_labels: &[impl Display],
+        matrix: &[impl AsRef<[T]>],
+    ) -> fmt::Result {
+        let mut table = TableBuilder::new();
+        let corner = iter::once(String::new());
+        table.header(corner.chain(column_labels.iter().map(ToString::to_string)));
+        for (i, row) in matrix.iter().enumerate() {
+            let label = row_labels
+                .get(i)
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            let label = Cell::new(label).with_alignment(Alignment::Left);
+            table.styled_row(iter::once(label).chain(row.as_ref().iter().map(Cell::new)));
+        }
+        self.write_table_builder(w, &table)
+    }
+
+    /// Format a table with a row for each value, using [`ToRow::headers`] as the header
+    ///
+    /// See [`ToRow`] for example and more info
+    fn format_rows<T: ToRow>(&self, rows: &[T]) -> String {
+        write_to_string(|w| self.write_rows(w, rows))
+    }
+
+    /// Write a table into `w` with a row for each value, using [`ToRow::headers`] as the header
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`ToRow`] for example and more info
+    fn write_rows<T: ToRow>(&self, w: &mut (impl fmt::Write + ?Sized), rows: &[T]) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            TableBuilder::new()
+                .header(T::headers())
+                .rows(rows.iter().map(ToRow::to_row)),
+        )
+    }
+
+    /// Format records (such as a list of maps, like the objects returned by an API or the rows of
+    /// a database query) as a table with a column for each key
+    ///
+    /// The columns are every key that appears in any record, in the order they are first seen, so
+    /// records with an unordered map such as a [`HashMap`] may have their columns in any order.
+    /// Records that are missing a key are left blank in that column.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let records = vec![
+    ///     vec![("name", "Jon"), ("age", "38")],
+    ///     vec![("name", "Jake"), ("town", "Springfield")],
+    /// ];
+    ///
+    /// println!("{}", styles::THIN.format_records(records));
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┬───────────┐
+    /// │name│age│       town│
+    /// ├────┼───┼───────────┤
+    /// │ Jon│ 38│           │
+    /// ├────┼───┼───────────┤
+    /// │Jake│   │Springfield│
+    /// └────┴───┴───────────┘
+    /// ```
+    fn format_records<K: Display, V: Display>(
+        &self,
+        records: impl IntoIterator<Item = impl IntoIterator<Item = (K, V)>>,
+    ) -> String {
+        write_to_string(|w| self.write_records(w, records))
+    }
+
+    /// Write records into `w` as a table with a column for each key
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_records`] for example and more info
+    fn write_records<K: Display, V: Display>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        records: impl IntoIterator<Item = impl IntoIterator<Item = (K, V)>>,
+    ) -> fmt::Result {
+        let mut columns: Vec<String> = Vec::new();
+        let records: Vec<Vec<_>> = records
+            .into_iter()
+            .map(|record| {
+                record
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let key = key.to_string();
+                        let col = match columns.iter().position(|column| *column == key) {
+                            Some(col) => col,
+                            None => {
+                                columns.push(key);
+                                columns.len() - 1
+                            }
+                        };
+                        (col, value.to_string())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let rows = records.into_iter().map(|record| {
+            let mut row = vec![String::new(); columns.len()];
+            for (col, value) in record {
+                row[col] = value;
+            }
+            row
+        });
+        self.write_table_builder(w, TableBuilder::new().header(&columns).rows(rows))
+    }
+
+    /// Format any value that implements [`serde::Serialize`] as nested tables
+    ///
+    /// Maps and structs become key/value tables, sequences become a table with one entry per
+    /// row, and everything else is displayed as-is.  The nested tables are added with
+    /// [`table::Cell::table`], so styles such as [`styles::HTML`] draw them as tables of their own.
+    /// See [`ser`] for more info.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// #[derive(serde::Serialize)]
+    /// struct Config {
+    ///     name: &'static str,
+    ///     ports: Vec<u16>,
+    /// }
+    ///
+    /// let config = Config { name: "server", ports: vec![80, 443] };
+    /// println!("{}", styles::THIN.format_serialize(&config).unwrap());
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌─────┬──────┐
+    /// │ name│server│
+    /// ├─────┼──────┤
+    /// │ports│80    │
+    /// │     ├──────┤
+    /// │     │443   │
+    /// └─────┴──────┘
+    /// ```
+    #[cfg(feature = "serde")]
+    fn format_serialize(
+        &self,
+        value: &(impl serde::Serialize + ?Sized),
+    ) -> Result<String, ser::Error> {
+        let mut out = String::new();
+        self.write_serialize(&mut out, value)?;
+        Ok(out)
+    }
+
+    /// Write any value that implements [`serde::Serialize`] into `w` as nested tables
+    ///
+    /// See [`Self::format_serialize`] for example and more info
+    #[cfg(feature = "serde")]
+    fn write_serialize(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        value: &(impl serde::Serialize + ?Sized),
+    ) -> Result<(), ser::Error> {
+        match ser::Node::from_serialize(value)? {
+            ser::Node::Scalar(s) => self.write_display(w, s)?,
+            node => {
+                if let Some(table) = node.table() {
+                    self.write_table_builder(w, &table)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Format a JSON value as nested tables
+    ///
+    /// Objects become key/value tables, arrays of objects become a table with a column for each
+    /// key, and any other array becomes a table with one item per row.  Strings are displayed
+    /// without their quotes, and everything else is displayed as JSON.  The nested tables are
+    /// added with [`table::Cell::table`], so styles such as [`styles::HTML`] draw them as tables
+    /// of their own.
+    ///
+    /// Requires the `json` feature.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let value = serde_json::json!({
+    ///     "name": "borderrs",
+    ///     "authors": [
+    ///         { "name": "Jon", "age": 38 },
+    ///         { "name": "Jake", "age": 25 },
+    ///     ],
+    ///     "keywords": ["cli", "terminal"],
+    /// });
+    /// println!("{}", styles::THIN.format_json(&value));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌────────┬───┬────┐
+    /// │ authors│age│name│
+    /// │        ├───┼────┤
+    /// │        │38 │Jon │
+    /// │        ├───┼────┤
+    /// │        │25 │Jake│
+    /// ├────────┼───┴────┤
+    /// │keywords│cli     │
+    /// │        ├────────┤
+    /// │        │terminal│
+    /// ├────────┼────────┤
+    /// │    name│borderrs│
+    /// └────────┴────────┘
+    /// ```
+    #[cfg(feature = "json")]
+    fn format_json(&self, value: &serde_json::Value) -> String {
+        write_to_string(|w| self.write_json(w, value))
+    }
+
+    /// Write a JSON value into `w` as nested tables
+    ///
+    /// See [`Self::format_json`] for example and more info
+    #[cfg(feature = "json")]
+    fn write_json(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        value: &serde_json::Value,
+    ) -> fmt::Result {
+        match json::table(value) {
+            Some(table) => self.write_table_builder(w, &table),
+            None => self.write_display(w, json::text(value)),
+        }
+    }
+
+    /// Format the result of an SQLite query as a table, using the names of its columns as the
+    /// header
+    ///
+    /// `NULL`s are left empty and blobs are drawn as SQL hex literals.  Reading the rows can fail,
+    /// in which case the error is returned instead of a partial table.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let db = rusqlite::Connection::open_in_memory()?;
+    /// db.execute_batch(
+    ///     "CREATE TABLE people (name TEXT, age INTEGER);
+    ///      INSERT INTO people VALUES ('Jon', 38), ('Jake', 25), ('Josh', NULL);",
+    /// )?;
+    ///
+    /// let mut query = db.prepare("SELECT name, age FROM people")?;
+    /// println!("{}", styles::THIN.format_sqlite_rows(query.query([])?)?);
+    /// # Ok::<_, rusqlite::Error>(())
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┐
+    /// │name│age│
+    /// ├────┼───┤
+    /// │ Jon│ 38│
+    /// ├────┼───┤
+    /// │Jake│ 25│
+    /// ├────┼───┤
+    /// │Josh│   │
+    /// └────┴───┘
+    /// ```
+    #[cfg(feature = "sqlite")]
+    fn format_sqlite_rows(&self, rows: rusqlite::Rows<'_>) -> rusqlite::Result<String> {
+        let table = TableBuilder::try_from(rows)?;
+        Ok(write_to_string(|w| self.write_table_builder(w, &table)))
+    }
+
+    /// Format an Arrow [`RecordBatch`](arrow_array::RecordBatch) as a table, with a column for
+    /// each of its fields
+    ///
+    /// Columns of numbers are right-aligned and every other column is left-aligned.  If the batch
+    /// has more than `max_rows` rows, only the first and last of them are shown, with a row of `…`
+    /// between them.  Nulls are left empty.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let names: ArrayRef = Arc::new(StringArray::from(vec!["Jon", "Jake", "Josh", "Finn"]));
+    /// let ages: ArrayRef = Arc::new(Int32Array::from(vec![Some(38), Some(25), None, Some(14)]));
+    /// let batch = RecordBatch::try_from_iter([("name", names), ("age", ages)])?;
+    ///
+    /// println!("{}", styles::THIN.format_record_batch(&batch, 2)?);
+    /// # Ok::<_, arrow_schema::ArrowError>(())
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┐
+    /// │name│age│
+    /// ├────┼───┤
+    /// │Jon │ 38│
+    /// ├────┼───┤
+    /// │ …  │ … │
+    /// ├────┼───┤
+    /// │Finn│ 14│
+    /// └────┴───┘
+    /// ```
+    #[cfg(feature = "arrow")]
+    fn format_record_batch(
+        &self,
+        batch: &arrow_array::RecordBatch,
+        max_rows: usize,
+    ) -> Result<String, arrow_schema::ArrowError> {
+        let table = record_batch::table(batch, max_rows)?;
+        Ok(write_to_string(|w| self.write_table_builder(w, &table)))
+    }
+
+    /// Write a table that was built using a [`TableBuilder`] into `w`
+    ///
+    /// This is what every other table-formatting method ends up calling, and it is usually
+    /// easier to call [`TableBuilder::build`] or [`TableBuilder::write`] than to call it
+    /// directly.
+    fn write_table_builder(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        table: &TableBuilder,
+    ) -> fmt::Result;
+
+    /// Add a border around anything that implements Display
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::BorderFormatter;
+    /// println!("{}", borderrs::styles::DOUBLE.format_display(" Hello World! "));
+    /// ```
+    /// produces
+    /// ```text
+    /// ╔══════════════╗
+    /// ║ Hello World! ║
+    /// ╚══════════════╝
+    /// ```
+    fn format_display(&self, val: impl Display) -> String {
+        write_to_string(|w| self.write_display(w, val))
+    }
+
+    /// Write anything that implements Display into `w` with a border around it
+    ///
+    /// See [`Self::format_display`] for example and more info
+    fn write_display(&self, w: &mut (impl fmt::Write + ?Sized), val: impl Display) -> fmt::Result {
+        self.write_slice(w, &[format!("{}", val)])
+    }
+
+    /// Add a border around text that is wrapped so that the whole box, including its borders, is
+    /// `width` columns wide
+    ///
+    /// Lines are broken between words where possible, and the explicit line breaks in `text` are
+    /// kept, so blank lines can be used to separate paragraphs.  Use a [`Panel`](panel::Panel) to
+    /// hyphenate long words, add padding, or add a title.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let text = "Add stylish borders around your text and datastructures.\n\nWorks without std.";
+    /// println!("{}", styles::THIN.format_text(text, 24));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌──────────────────────┐
+    /// │Add stylish borders   │
+    /// │around your text and  │
+    /// │datastructures.       │
+    /// │                      │
+    /// │Works without std.    │
+    /// └──────────────────────┘
+    /// ```
+    fn format_text(&self, text: impl Display, width: usize) -> String {
+        write_to_string(|w| self.write_text(w, text, width))
+    }
+
+    /// Write text into `w` with a border around it, wrapped so that the whole box is `width`
+    /// columns wide
+    ///
+    /// The default implementation builds a [`Panel`](panel::Panel) and passes its table to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_text`] for example and more info
+    fn write_text(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        text: impl Display,
+        width: usize,
+    ) -> fmt::Result {
+        self.write_table_builder(
+            w,
+            &panel::Panel::new(text)
+                .width(width)
+                .table(self.border_widths()),
+        )
+    }
+
+    /// Format a horizontal line that is `width` columns wide, drawn with the same piece as the top
+    /// and bottom borders, for separating sections of output
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// println!("{}", styles::DOUBLE.format_rule(12));
+    /// ```
+    /// produces
+    /// ```text
+    /// ════════════
+    /// ```
+    fn format_rule(&self, width: usize) -> String {
+        write_to_string(|w| self.write_rule(w, width))
+    }
+
+    /// Write a horizontal line that is `width` columns wide into `w`
+    ///
+    /// The default implementation draws the line with `-`.
+    ///
+    /// See [`Self::format_rule`] for example and more info
+    fn write_rule(&self, w: &mut (impl fmt::Write + ?Sized), width: usize) -> fmt::Result {
+        self.write_titled_rule(w, "", width)
+    }
+
+    /// Format a horizontal line that is `width` columns wide with `title` in the middle of it, for
+    /// starting a new section of output
+    ///
+    /// If the title doesn't fit, the line is only as wide as the title.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// println!("{}", styles::THIN.format_titled_rule("Section", 15));
+    /// ```
+    /// produces
+    /// ```text
+    /// ─── Section ───
+    /// ```
+    fn format_titled_rule(&self, title: impl Display, width: usize) -> String {
+        write_to_string(|w| self.write_titled_rule(w, title, width))
+    }
+
+    /// Write a horizontal line that is `width` columns wide into `w`, with `title` in the middle
+    /// of it
+    ///
+    /// The default implementation draws the line with `-`.
+    ///
+    /// See [`Self::format_titled_rule`] for example and more info
+    fn write_titled_rule(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        title: impl Display,
+        width: usize,
+    ) -> fmt::Result {
+        let (left, title, right) = rule_parts(title, width);
+        (0..left).try_for_each(|_| w.write_char('-'))?;
+        w.write_str(&title)?;
+        (0..right).try_for_each(|_| w.write_char('-'))
+    }
+
+    /// Add a border around anything that implements Debug
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::BorderFormatter;
+    /// println!("{}", borderrs::styles::DOUBLE.format_debug("Hello World!"));
+    /// ```
+    /// produces
+    /// ```text
+    /// ╔══════════════╗
+    /// ║"Hello World!"║
+    /// ╚══════════════╝
+    /// ```
+    fn format_debug(&self, val: impl Debug) -> String {
+        write_to_string(|w| self.write_debug(w, val))
+    }
+
+    /// Write anything that implements Debug into `w` with a border around it
+    ///
+    /// See [`Self::format_debug`] for example and more info
+    fn write_debug(&self, w: &mut (impl fmt::Write + ?Sized), val: impl Debug) -> fmt::Result {
+        self.write_slice(w, &[format!("{:?}", val)])
+    }
+
+    /// Add a border around anything that implements Debug, using its pretty-printed (`{:#?}`)
+    /// form
+    ///
+    /// Each line of the pretty-printed value is left-aligned so that nested values stay readable.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::BorderFormatter;
+    /// #[derive(Debug)]
+    /// struct Person {
+    ///     name: &'static str,
+    ///     age: u8,
+    /// }
+    ///
+    /// let jon = Person { name: "Jon", age: 38 };
+    /// println!("{}", borderrs::styles::THIN.format_debug_pretty(jon));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌────────────────┐
+    /// │Person {        │
+    /// │    name: "Jon",│
+    /// │    age: 38,    │
+    /// │}               │
+    /// └────────────────┘
+    /// ```
+    fn format_debug_pretty(&self, val: impl Debug) -> String {
+        write_to_string(|w| self.write_debug_pretty(w, val))
+    }
+
+    /// Write anything that implements Debug into `w` with a border around it, using its
+    /// pretty-printed (`{:#?}`) form
+    ///
+    /// See [`Self::format_debug_pretty`] for example and more info
+    fn write_debug_pretty(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        val: impl Debug,
+    ) -> fmt::Result {
+        self.write_slice_aligned(w, &[format!("{:#?}", val)], Alignment::Left)
+    }
+
+    /// Lay out already formatted `blocks` (such as tables) next to each other with their tops
+    /// lined up, separated by `gap` spaces
+    ///
+    /// Each block is padded to the width of its widest line, and blocks that are shorter than the
+    /// tallest one are padded with blank lines, so the blocks don't need to have the same size.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let before = styles::THIN.format_table(&[vec!["a", "b"]]);
+    /// let after = styles::THIN.format_table(&[vec!["a", "b"], vec!["c", "d"]]);
+    /// println!("{}", styles::THIN.format_side_by_side(&[before, after], 2));
+    /// ```
+    /// produces
+    /// ```text
+    /// ┌─┬─┐  ┌─┬─┐
+    /// │a│b│  │a│b│
+    /// └─┴─┘  ├─┼─┤
+    ///        │c│d│
+    ///        └─┴─┘
+    /// ```
+    fn format_side_by_side(&self, blocks: &[impl Display], gap: usize) -> String {
+        write_to_string(|w| self.write_side_by_side(w, blocks, gap))
+    }
 
-    /// Add a border around anything that implements Display
+    /// Write already formatted `blocks` into `w` next to each other with their tops lined up,
+    /// separated by `gap` spaces
+    ///
+    /// The default implementation lines the blocks up as plain text without adding any borders.
+    ///
+    /// See [`Self::format_side_by_side`] for example and more info
+    fn write_side_by_side(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        blocks: &[impl Display],
+        gap: usize,
+    ) -> fmt::Result {
+        let blocks: Vec<_> = blocks.iter().map(ToString::to_string).collect();
+        let lines: Vec<Vec<_>> = blocks.iter().map(|block| block.lines().collect()).collect();
+        let widths: Vec<_> = lines
+            .iter()
+            .map(|lines| lines.iter().map(|line| width::display_width(line)).max())
+            .map(Option::unwrap_or_default)
+            .collect();
+        let height = lines.iter().map(Vec::len).max().unwrap_or(0);
+
+        for i in 0..height {
+            if i > 0 {
+                w.write_char('\n')?;
+            }
+            // Leave off the padding after the last block so that lines don't end with spaces
+            let last = lines.iter().rposition(|lines| i < lines.len()).unwrap_or(0);
+            for (col, (lines, &width)) in lines.iter().zip(&widths).enumerate().take(last + 1) {
+                if col > 0 {
+                    write_spaces(w, gap)?;
+                }
+                let line = lines.get(i).copied().unwrap_or("");
+                match col == last {
+                    true => w.write_str(line)?,
+                    false => Alignment::Left.write_aligned(w, line, width)?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Format the differences between two lists of lines as a table, with the `left` lines in the
+    /// first column, the `right` lines in the last column, and a marker in the gutter between them
+    ///
+    /// Lines that are the same on both sides are lined up with an empty gutter.  Lines that are
+    /// only on the left are marked with `-` in red, lines that are only on the right with `+` in
+    /// green, and lines that were replaced with `~` in both colors.
     ///
     /// For example,
     /// ```rust
-    /// # use borderrs::BorderFormatter;
-    /// println!("{}", borderrs::styles::DOUBLE.format_display(" Hello World! "));
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let expected = ["one", "two", "three", "four"];
+    /// let actual = ["one", "2", "three", "four", "five"];
+    /// println!("{}", styles::THIN.format_diff(&expected, &actual));
+    /// ```
+    /// produces the output (with the changed lines in color)
+    /// ```text
+    /// ┌─────┬─┬─────┐
+    /// │one  │ │one  │
+    /// │two  │~│2    │
+    /// │three│ │three│
+    /// │four │ │four │
+    /// │     │+│five │
+    /// └─────┴─┴─────┘
+    /// ```
+    fn format_diff(&self, left: &[impl Display], right: &[impl Display]) -> String {
+        write_to_string(|w| self.write_diff(w, left, right))
+    }
+
+    /// Write the differences between two lists of lines into `w` as a table, with a marker for
+    /// each change in the gutter between them
+    ///
+    /// The default implementation builds a [`TableBuilder`] and passes it to
+    /// [`Self::write_table_builder`].
+    ///
+    /// See [`Self::format_diff`] for example and more info
+    fn write_diff(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        left: &[impl Display],
+        right: &[impl Display],
+    ) -> fmt::Result {
+        let left: Vec<_> = left.iter().map(ToString::to_string).collect();
+        let right: Vec<_> = right.iter().map(ToString::to_string).collect();
+        let left: Vec<_> = left.iter().map(String::as_str).collect();
+        let right: Vec<_> = right.iter().map(String::as_str).collect();
+
+        let mut table = TableBuilder::new();
+        table
+            .alignments(&[Alignment::Left, Alignment::Center, Alignment::Left])
+            .row_separator(RowSeparator::None);
+        for change in diff::diff(&left, &right) {
+            let removed = |line| Cell::new(line).with_color(Color::Red);
+            let added = |line| Cell::new(line).with_color(Color::Green);
+            table.styled_row(match change {
+                Change::Same(line) => [Cell::new(line), Cell::default(), Cell::new(line)],
+                Change::Removed(line) => [removed(line), removed("-"), Cell::default()],
+                Change::Added(line) => [Cell::default(), added("+"), added(line)],
+                Change::Changed(before, after) => [removed(before), Cell::new("~"), added(after)],
+            });
+        }
+        self.write_table_builder(w, &table)
+    }
+
+    /// Check that the style can draw tables without breaking them, such as by having junctions
+    /// that are wider than the lines they join
+    ///
+    /// The default implementation accepts every style.
+    fn validate(&self) -> Result<(), BorderError> {
+        Ok(())
+    }
+
+    /// Get how many columns the vertical borders of the tables drawn in this style take up
+    ///
+    /// This is used to check that a table fits within the width given to
+    /// [`TableBuilder::auto_fit`] or [`TableBuilder::total_width`] in [`TableBuilder::try_build`].
+    /// The default implementation takes every border to be one column wide.
+    fn border_widths(&self) -> BorderWidths {
+        BorderWidths::default()
+    }
+
+    /// Format a slice into an horizontal table, returning an error instead of producing broken
+    /// output
+    ///
+    /// This fails if the style is invalid (see [`Self::validate`]), or if any entry contains a
+    /// control character other than a line break, such as a tab or NUL, since those move the
+    /// cursor in ways that can't be measured.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{error::BorderError, styles, BorderFormatter};
+    /// assert!(styles::THIN.try_format_slice(&["Hello", "world"]).is_ok());
+    /// assert_eq!(
+    ///     styles::THIN.try_format_slice(&["Hello\tworld"]),
+    ///     Err(BorderError::ControlCharacter('\t')),
+    /// );
+    /// ```
+    ///
+    /// See [`Self::format_slice`] for more info
+    fn try_format_slice(&self, slice: &[impl Display]) -> Result<String, BorderError> {
+        self.validate()?;
+        let slice: Vec<_> = slice.iter().map(ToString::to_string).collect();
+        slice
+            .iter()
+            .try_for_each(|entry| error::check_text(entry))?;
+        Ok(self.format_slice(&slice))
+    }
+
+    /// Format a 2D table where each inner [`Vec`] is a row of cells, returning an error instead
+    /// of producing broken output
+    ///
+    /// See [`Self::try_format_slice`] for when this fails, and [`Self::format_table`] for more
+    /// info
+    fn try_format_table(&self, rows: &[Vec<impl Display>]) -> Result<String, BorderError> {
+        self.try_format_table_with_headers(&[] as &[&str], rows)
+    }
+
+    /// Format a 2D table with a header row on top, returning an error instead of producing
+    /// broken output
+    ///
+    /// See [`Self::try_format_slice`] for when this fails, and
+    /// [`Self::format_table_with_headers`] for more info
+    fn try_format_table_with_headers(
+        &self,
+        headers: &[impl AsRef<str>],
+        rows: &[Vec<impl Display>],
+    ) -> Result<String, BorderError> {
+        self.validate()?;
+        let rows: Vec<Vec<_>> = rows
+            .iter()
+            .map(|row| row.iter().map(ToString::to_string).collect())
+            .collect();
+        let text = headers.iter().map(AsRef::as_ref);
+        text.chain(rows.iter().flatten().map(String::as_str))
+            .try_for_each(error::check_text)?;
+        Ok(self.format_table_with_headers(headers, &rows))
+    }
+
+    /// Lazily add a border around anything that implements Display
+    ///
+    /// Nothing is formatted until the returned [`Bordered`] is displayed, at which point it is
+    /// written straight into the formatter using [`Self::write_display`].
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let rows = vec![vec!["a", "b"], vec!["c", "d"]];
+    /// println!("{}", styles::DOUBLE.display(&styles::THIN.table(&rows)));
     /// ```
     /// produces
     /// ```text
-    /// ╔══════════════╗
-    /// ║ Hello World! ║
-    /// ╚══════════════╝
+    /// ╔═════╗
+    /// ║┌─┬─┐║
+    /// ║│a│b│║
+    /// ║├─┼─┤║
+    /// ║│c│d│║
+    /// ║└─┴─┘║
+    /// ╚═════╝
     /// ```
-    fn format_display(&self, val: impl Display) -> String {
-        self.format_slice(&[format!("{}", val)])
+    fn display<'a, T: Display + ?Sized>(&'a self, value: &'a T) -> Bordered<'a, Self, T> {
+        Bordered::new(self, value)
     }
 
-    /// Add a border around anything that implements Debug
+    /// Lazily format a slice into an horizontal table
+    ///
+    /// Nothing is formatted until the returned [`BorderedSlice`] is displayed, at which point it
+    /// is written straight into the formatter using [`Self::write_slice`].
+    ///
+    /// See [`Self::format_slice`] for more info
+    fn slice<'a, T: Display>(&'a self, slice: &'a [T]) -> BorderedSlice<'a, Self, T> {
+        BorderedSlice::new(self, slice)
+    }
+
+    /// Lazily format a 2D table where each inner [`Vec`] is a row of cells
+    ///
+    /// Nothing is formatted until the returned [`BorderedTable`] is displayed, at which point it
+    /// is written straight into the formatter using [`Self::write_table`].
+    ///
+    /// See [`Self::format_table`] for more info
+    fn table<'a, T: Display>(&'a self, rows: &'a [Vec<T>]) -> BorderedTable<'a, Self, T> {
+        BorderedTable::new(self, rows)
+    }
+
+    /// Lazily format a 2D table for a field of a log message, such as with `log` or `tracing`
+    ///
+    /// Nothing is formatted unless the message is actually logged, so no work is wasted when its
+    /// level is disabled.  The returned [`TableField`] starts with a line break so that the table
+    /// isn't pushed out of line by the rest of the log line, and it can be used with both `{}`
+    /// and `{:?}` (or `%` and `?` in `tracing`).
     ///
     /// For example,
     /// ```rust
-    /// # use borderrs::BorderFormatter;
-    /// println!("{}", borderrs::styles::DOUBLE.format_debug("Hello World!"));
+    /// # use borderrs::{styles, BorderFormatter};
+    /// let rows = vec![vec!["Jon", "38"], vec!["Jake", "25"]];
+    /// // With `log`, this would be `log::debug!("users: {}", styles::THIN.as_table_field(&rows))`
+    /// println!("users: {}", styles::THIN.as_table_field(&rows));
     /// ```
     /// produces
     /// ```text
-    /// ╔══════════════╗
-    /// ║"Hello World!"║
-    /// ╚══════════════╝
+    /// users:
+    /// ┌────┬──┐
+    /// │ Jon│38│
+    /// ├────┼──┤
+    /// │Jake│25│
+    /// └────┴──┘
     /// ```
-    fn format_debug(&self, val: impl Debug) -> String {
-        self.format_slice(&[format!("{:?}", val)])
+    ///
+    /// See [`Self::format_table`] for more info
+    fn as_table_field<'a, T: Display>(&'a self, rows: &'a [Vec<T>]) -> TableField<'a, Self, T> {
+        TableField::new(self, rows)
     }
 }
 
-impl SimpleBorderStyle {
-    /// Get the top line for a horizontal table with a consistent width
-    fn get_top_line(&self, len: usize, width: usize) -> String {
-        format!(
-            "{}{}{}",
-            self.top_left,
-            (0..len)
-                .map(|_| self.horizontal.to_string().repeat(width))
-                .collect::<Vec<_>>()
-                .join(&self.horizontal_down.to_string()),
-            self.top_right
-        )
+/// Split a rule that is `width` columns wide into the number of columns of line to the left of
+/// `title`, the title with a space on either side (or nothing if it is empty), and the number of
+/// columns of line to the right of it
+fn rule_parts(title: impl Display, width: usize) -> (usize, String, usize) {
+    let title = title.to_string();
+    let title = match title.is_empty() {
+        true => title,
+        false => format!(" {} ", title),
+    };
+    let rest = width.saturating_sub(width::display_width(&title));
+    (rest / 2, title, rest - rest / 2)
+}
+
+/// Get the text of `cell`, or `placeholder` if its value is missing
+fn or_placeholder(cell: &impl OptionalCell, placeholder: &str) -> String {
+    match cell.value() {
+        Some(value) => value.to_string(),
+        None => placeholder.to_string(),
+    }
+}
+
+/// Group key/value pairs by key for [`BorderFormatter::format_multimap`], keeping the keys in
+/// the order they first appear and the values of each key in the order they are given
+fn group_pairs(
+    pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+) -> Vec<(String, Vec<String>)> {
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    let mut indices = BTreeMap::new();
+    for (key, value) in pairs {
+        let key = key.to_string();
+        let index = *indices.entry(key.clone()).or_insert_with(|| {
+            groups.push((key, Vec::new()));
+            groups.len() - 1
+        });
+        groups[index].1.push(value.to_string());
     }
+    groups
+}
 
-    /// Get the bottom line for a horizontal table with a consistent width
-    fn get_bottom_line(&self, len: usize, width: usize) -> String {
-        format!(
-            "{}{}{}",
-            self.bottom_left,
-            (0..len)
-                .map(|_| self.horizontal.to_string().repeat(width))
-                .collect::<Vec<_>>()
-                .join(&self.horizontal_up.to_string()),
-            self.bottom_right,
-        )
+/// Collect the output of a `write_*` method into a new [`String`]
+fn write_to_string(write: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut out = String::new();
+    write(&mut out).expect("writing to a String can't fail");
+    out
+}
+
+/// Implement [`BorderFormatter`] for pointers to a formatter, forwarding every method that a
+/// style can override so that the pointer formats exactly like the style it points to
+macro_rules! impl_border_formatter_for_pointer {
+    ($($pointer:ty),*) => {$(
+        impl<S: BorderFormatter + ?Sized> BorderFormatter for $pointer {
+            fn format_slice(&self, slice: &[impl Display]) -> String {
+                (**self).format_slice(slice)
+            }
+
+            fn format_slice_aligned(&self, slice: &[impl Display], alignment: Alignment) -> String {
+                (**self).format_slice_aligned(slice, alignment)
+            }
+
+            fn write_slice_aligned(
+                &self,
+                w: &mut (impl fmt::Write + ?Sized),
+                slice: &[impl Display],
+                alignment: Alignment,
+            ) -> fmt::Result {
+                (**self).write_slice_aligned(w, slice, alignment)
+            }
+
+            fn write_table_builder(
+                &self,
+                w: &mut (impl fmt::Write + ?Sized),
+                table: &TableBuilder,
+            ) -> fmt::Result {
+                (**self).write_table_builder(w, table)
+            }
+
+            fn write_titled_rule(
+                &self,
+                w: &mut (impl fmt::Write + ?Sized),
+                title: impl Display,
+                width: usize,
+            ) -> fmt::Result {
+                (**self).write_titled_rule(w, title, width)
+            }
+
+            fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+                &self,
+                w: &mut (impl fmt::Write + ?Sized),
+                root: &'a T,
+                label: impl Fn(&'a T) -> L,
+                children: impl Fn(&'a T) -> C,
+            ) -> fmt::Result {
+                (**self).write_tree_with(w, root, label, children)
+            }
+
+            #[cfg(feature = "std")]
+            fn format_hash_map_headers(
+                &self,
+                map: &HashMap<impl Display, impl Display>,
+                key_header: impl AsRef<str>,
+                value_header: impl AsRef<str>,
+            ) -> String {
+                (**self).format_hash_map_headers(map, key_header, value_header)
+            }
+
+            #[cfg(feature = "std")]
+            fn stream_rows<R: IntoIterator<Item = impl Display>>(
+                &self,
+                w: &mut (impl io::Write + ?Sized),
+                rows: impl IntoIterator<Item = R>,
+                widths: &[usize],
+            ) -> io::Result<()> {
+                (**self).stream_rows(w, rows, widths)
+            }
+
+            fn validate(&self) -> Result<(), BorderError> {
+                (**self).validate()
+            }
+
+            fn border_widths(&self) -> BorderWidths {
+                (**self).border_widths()
+            }
+        }
+    )*};
+}
+
+impl_border_formatter_for_pointer!(&S, &mut S, Box<S>, Rc<S>, Arc<S>);
+
+impl Frame for SimpleBorderStyle {
+    fn write_piece(&self, w: &mut (impl fmt::Write + ?Sized), piece: Piece) -> fmt::Result {
+        w.write_char(self.piece(piece))
+    }
+
+    fn piece_width(&self, piece: Piece) -> usize {
+        self.piece(piece).width().unwrap_or(0)
+    }
+
+    fn border_color(&self) -> Option<Color> {
+        self.border_color
     }
 }
 
 impl BorderFormatter for SimpleBorderStyle {
+    fn validate(&self) -> Result<(), BorderError> {
+        self.check_pieces()
+    }
+
+    fn border_widths(&self) -> BorderWidths {
+        self.frame_widths()
+    }
+
     fn format_slice(&self, slice: &[impl Display]) -> String {
-        // Format all values using [`Display`] (via `format!`)
-        let entries: Vec<_> = slice.iter().map(|v| format!("{}", v)).collect();
-        // Split into lines so we can do processing later
-        let entry_lines: Vec<_> = entries.iter().map(|n| n.lines()).collect();
+        self.render_slice(slice, Alignment::Right)
+    }
 
-        // Get the height of the row
-        let lines = entry_lines
-            .iter()
-            .map(|n| n.clone().count())
-            .max()
-            .unwrap_or(1);
+    fn format_slice_aligned(&self, slice: &[impl Display], alignment: Alignment) -> String {
+        self.render_slice(slice, alignment)
+    }
 
-        // Get the width of each column
-        let len = entry_lines
-            .iter()
-            .map(|n| n.clone().map(|l| l.chars().count()).max().unwrap_or(0))
-            .max()
-            .unwrap_or(1);
-
-        // Get the top/bottom lines
-        let top_line = self.get_top_line(entries.len(), len);
-        let bottom_line = self.get_bottom_line(entries.len(), len);
-
-        let mut middle = String::new();
-        for i in 0..lines {
-            // Format each line
-            middle += &format!(
-                "{vert}{}{vert}\n",
-                entry_lines
-                    .iter()
-                    // TODO: Figure out how to do this without cloning so much, it hurts my heart :(
-                    .map(|l| format!("{:>len$}", l.clone().nth(i).unwrap_or(""), len = len))
-                    .collect::<Vec<_>>()
-                    .join(&self.vertical.to_string()),
-                vert = self.vertical
-            )
-        }
+    fn write_slice_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        alignment: Alignment,
+    ) -> fmt::Result {
+        self.draw_slice(w, slice, alignment)
+    }
+
+    fn write_table_builder(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        table: &TableBuilder,
+    ) -> fmt::Result {
+        self.draw_table(w, table)
+    }
 
-        format!("{}\n{}{}", top_line, middle, bottom_line)
+    fn write_titled_rule(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        title: impl Display,
+        width: usize,
+    ) -> fmt::Result {
+        self.draw_rule(w, title, width)
+    }
+
+    fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> fmt::Result {
+        tree::write_tree(w, root, &label, &children, &self.tree_guides())
     }
 
+    #[cfg(feature = "std")]
     fn format_hash_map_headers(
         &self,
         map: &HashMap<impl Display, impl Display>,
         key_header: impl AsRef<str>,
         value_header: impl AsRef<str>,
     ) -> String {
-        // Resolve the reference to cleanup code further down
-        let key_header = key_header.as_ref();
-        let value_header = value_header.as_ref();
+        self.render_table(
+            TableBuilder::new()
+                .header([key_header.as_ref(), value_header.as_ref()])
+                .pairs(map),
+        )
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_rows<R: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        self.draw_stream(w, rows, widths)
+    }
+}
 
-        // Format all of the values using [`Display`] (via `format!`)
-        let vals: Vec<_> = map.values().map(|v| format!("{}", v)).collect();
-        // Split them into their lines so we can do processing later
-        let vals: Vec<_> = vals.iter().map(|v| v.lines()).collect();
+/// Represents a border style where each kind of line is a string, rather than a single character
+///
+/// This allows for borders that are more than one column wide, such as separators with spacing
+/// around them or double-width glyphs.  The horizontal pieces are repeated as many times as they
+/// fit into each column, and any columns left over are filled with spaces.
+///
+/// The junctions and corners should be as wide as the vertical lines they connect to, otherwise
+/// the lines won't match up.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{BorderFormatter, StringBorderStyle};
+/// const STYLE: StringBorderStyle = StringBorderStyle::new(" | ", "-", "-+-");
+///
+/// println!("{}", STYLE.format_table_with_headers(&["Name", "Age"], &[vec!["Jon", "38"]]));
+/// ```
+/// produces the output
+/// ```text
+/// -+------+-----+-
+///  | Name | Age |
+/// -+------+-----+-
+///  |  Jon |  38 |
+/// -+------+-----+-
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StringBorderStyle {
+    /// Used as the vertical line on the left and right edges
+    vertical: Cow<'static, str>,
+    /// Used as the horizontal line on the top and bottom edges, repeated to fill each column
+    horizontal: Cow<'static, str>,
 
-        // Get the longest value's length to use as the column width
-        let val_width = vals
-            .iter()
-            .map(|l| l.clone().map(|v| v.len()).max().unwrap_or(1))
-            .max()
-            .unwrap_or(1)
-            .max(value_header.len());
-
-        // Format all keys using [`Display`] (via `format!`)
-        let keys: Vec<_> = map.keys().map(|k| format!("{}", k)).collect();
-        // Split them into their lines so we can do processing later
-        let keys: Vec<_> = keys.iter().map(|k| k.lines()).collect();
-
-        // Get the longest key's length to use as the column width
-        let key_width = keys
-            .iter()
-            .map(|l| l.clone().map(|k| k.len()).max().unwrap_or(1))
-            .max()
-            .unwrap_or(1)
-            .max(key_header.len());
-
-        // Format the top line using the widths calculuated and the values in the struct
-        let top_line = format!(
-            "{}{}{}{}{}",
-            self.top_left,
-            self.horizontal.to_string().repeat(key_width),
-            self.horizontal_down,
-            self.horizontal.to_string().repeat(val_width),
-            self.top_right
-        );
-
-        // Format the bottom line using the widths calculuated and the values in the struct
-        let bottom_line = format!(
-            "{}{}{}{}{}",
-            self.bottom_left,
-            self.horizontal.to_string().repeat(key_width),
-            self.horizontal_up,
-            self.horizontal.to_string().repeat(val_width),
-            self.bottom_right
-        );
-
-        let mut entries: Vec<_> = keys
-            .iter()
-            .zip(vals)
-            .map(|(key, val)| (key.clone(), val))
-            .collect();
+    /// Used as a vertical separator between columns
+    inner_vertical: Cow<'static, str>,
+    /// Used as the horizontal separator between rows, repeated to fill each column
+    inner_horizontal: Cow<'static, str>,
+    /// Used as the horizontal separator between the header and the first row, repeated to fill
+    /// each column
+    header_horizontal: Cow<'static, str>,
+
+    /// Used when there is a line connecting up, left, and right
+    horizontal_up: Cow<'static, str>,
+    /// Used when there is a line connecting down, left, and right
+    horizontal_down: Cow<'static, str>,
+
+    /// Used when there is a line connecting up, down, and right
+    vertical_right: Cow<'static, str>,
+    /// Used when there is a line connecting up, down, and left
+    vertical_left: Cow<'static, str>,
+
+    /// Used when there is a line connecting down and right (top-left corner)
+    top_left: Cow<'static, str>,
+    /// Used when there is a line connecting down and left (top-right corner)
+    top_right: Cow<'static, str>,
+
+    /// Used when there is a line connecting up and right (bottom-left corner)
+    bottom_left: Cow<'static, str>,
+    /// Used when there is a line connecting up and left (bottom-right corner)
+    bottom_right: Cow<'static, str>,
+
+    /// Used where there is a line connecting in every direction
+    cross: Cow<'static, str>,
+
+    /// The color that the border is drawn in
+    #[cfg_attr(feature = "serde", serde(default))]
+    border_color: Option<Color>,
+}
+
+impl StringBorderStyle {
+    /// Create a style that uses `vertical` for every vertical line, `horizontal` for every
+    /// horizontal line, and `junction` for every corner and junction
+    ///
+    /// The individual pieces can then be changed using the `with_*` methods.
+    pub const fn new(
+        vertical: &'static str,
+        horizontal: &'static str,
+        junction: &'static str,
+    ) -> Self {
+        Self {
+            vertical: Cow::Borrowed(vertical),
+            horizontal: Cow::Borrowed(horizontal),
+
+            inner_vertical: Cow::Borrowed(vertical),
+            inner_horizontal: Cow::Borrowed(horizontal),
+            header_horizontal: Cow::Borrowed(horizontal),
 
-        // Put the header on the top of the table if they are provided
-        if !key_header.is_empty() || !value_header.is_empty() {
-            let mut new_entries = vec![(key_header.lines(), value_header.lines())];
-            new_entries.extend(entries);
-            entries = new_entries;
+            horizontal_up: Cow::Borrowed(junction),
+            horizontal_down: Cow::Borrowed(junction),
+
+            vertical_right: Cow::Borrowed(junction),
+            vertical_left: Cow::Borrowed(junction),
+
+            top_left: Cow::Borrowed(junction),
+            top_right: Cow::Borrowed(junction),
+
+            bottom_left: Cow::Borrowed(junction),
+            bottom_right: Cow::Borrowed(junction),
+
+            cross: Cow::Borrowed(junction),
+
+            border_color: None,
         }
+    }
 
-        let mut middle = String::new();
-        for i in 0..entries.len() {
-            let (ref mut key, ref mut val) = entries[i];
-            let height = key.clone().count().max(val.clone().count()); // The height of this row
-            for _ in 0..height {
-                // Add the line
-                middle += &format!(
-                    "{vert}{key:>key_width$}{vert}{val:>val_width$}{vert}\n",
-                    key = key.next().unwrap_or(""), // Get the next line or nothing if we're out of lines to grab
-                    val = val.next().unwrap_or(""), // ^
-                    key_width = key_width,
-                    val_width = val_width,
-                    vert = self.vertical
-                )
-            }
+    /// Get the string used for `piece`
+    fn piece(&self, piece: Piece) -> &str {
+        match piece {
+            Piece::Vertical => &self.vertical,
+            Piece::Horizontal => &self.horizontal,
+            Piece::InnerVertical => &self.inner_vertical,
+            Piece::InnerHorizontal => &self.inner_horizontal,
+            Piece::HeaderHorizontal => &self.header_horizontal,
+            Piece::HorizontalUp => &self.horizontal_up,
+            Piece::HorizontalDown => &self.horizontal_down,
+            Piece::VerticalRight => &self.vertical_right,
+            Piece::VerticalLeft => &self.vertical_left,
+            Piece::TopLeft => &self.top_left,
+            Piece::TopRight => &self.top_right,
+            Piece::BottomLeft => &self.bottom_left,
+            Piece::BottomRight => &self.bottom_right,
+            Piece::Cross => &self.cross,
+        }
+    }
 
-            // If we are before the last item
-            if i < entries.len() - 1 {
-                // Apply the middle line
-                middle += &format!(
-                    "{}{}{}{}{}\n",
-                    self.vertical_right,
-                    self.horizontal.to_string().repeat(key_width),
-                    self.cross,
-                    self.horizontal.to_string().repeat(val_width),
-                    self.vertical_left,
-                )
-            }
+    /// Draw the border in `color`, leaving the contents of the cells as they are
+    pub fn with_border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    /// Use `piece` as the vertical line on the left and right edges
+    pub fn with_vertical(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.vertical = piece.into();
+        self
+    }
+
+    /// Use `piece` as the horizontal line on the top and bottom edges
+    pub fn with_horizontal(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.horizontal = piece.into();
+        self
+    }
+
+    /// Use `piece` as the vertical separator between columns
+    pub fn with_inner_vertical(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.inner_vertical = piece.into();
+        self
+    }
+
+    /// Use `piece` as the horizontal separator between rows
+    pub fn with_inner_horizontal(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.inner_horizontal = piece.into();
+        self
+    }
+
+    /// Use `piece` as the horizontal separator between the header and the first row
+    pub fn with_header_horizontal(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.header_horizontal = piece.into();
+        self
+    }
+
+    /// Use `piece` as the junction connecting up, left, and right
+    pub fn with_horizontal_up(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.horizontal_up = piece.into();
+        self
+    }
+
+    /// Use `piece` as the junction connecting down, left, and right
+    pub fn with_horizontal_down(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.horizontal_down = piece.into();
+        self
+    }
+
+    /// Use `piece` as the junction connecting up, down, and right
+    pub fn with_vertical_right(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.vertical_right = piece.into();
+        self
+    }
+
+    /// Use `piece` as the junction connecting up, down, and left
+    pub fn with_vertical_left(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.vertical_left = piece.into();
+        self
+    }
+
+    /// Use `piece` as the top-left corner
+    pub fn with_top_left(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.top_left = piece.into();
+        self
+    }
+
+    /// Use `piece` as the top-right corner
+    pub fn with_top_right(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.top_right = piece.into();
+        self
+    }
+
+    /// Use `piece` as the bottom-left corner
+    pub fn with_bottom_left(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.bottom_left = piece.into();
+        self
+    }
+
+    /// Use `piece` as the bottom-right corner
+    pub fn with_bottom_right(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.bottom_right = piece.into();
+        self
+    }
+
+    /// Use `piece` as the junction connecting in every direction
+    pub fn with_cross(mut self, piece: impl Into<Cow<'static, str>>) -> Self {
+        self.cross = piece.into();
+        self
+    }
+}
+
+impl From<SimpleBorderStyle> for StringBorderStyle {
+    fn from(style: SimpleBorderStyle) -> Self {
+        Self {
+            vertical: Cow::Owned(style.vertical.to_string()),
+            horizontal: Cow::Owned(style.horizontal.to_string()),
+
+            inner_vertical: Cow::Owned(style.inner_vertical.to_string()),
+            inner_horizontal: Cow::Owned(style.inner_horizontal.to_string()),
+            header_horizontal: Cow::Owned(style.header_horizontal.to_string()),
+
+            horizontal_up: Cow::Owned(style.horizontal_up.to_string()),
+            horizontal_down: Cow::Owned(style.horizontal_down.to_string()),
+
+            vertical_right: Cow::Owned(style.vertical_right.to_string()),
+            vertical_left: Cow::Owned(style.vertical_left.to_string()),
+
+            top_left: Cow::Owned(style.top_left.to_string()),
+            top_right: Cow::Owned(style.top_right.to_string()),
+
+            bottom_left: Cow::Owned(style.bottom_left.to_string()),
+            bottom_right: Cow::Owned(style.bottom_right.to_string()),
+
+            cross: Cow::Owned(style.cross.to_string()),
+
+            border_color: style.border_color,
         }
+    }
+}
+
+impl Frame for StringBorderStyle {
+    fn write_piece(&self, w: &mut (impl fmt::Write + ?Sized), piece: Piece) -> fmt::Result {
+        w.write_str(self.piece(piece))
+    }
+
+    fn piece_width(&self, piece: Piece) -> usize {
+        width::display_width(self.piece(piece))
+    }
+
+    fn border_color(&self) -> Option<Color> {
+        self.border_color
+    }
+}
+
+impl BorderFormatter for StringBorderStyle {
+    fn validate(&self) -> Result<(), BorderError> {
+        self.check_pieces()
+    }
+
+    fn border_widths(&self) -> BorderWidths {
+        self.frame_widths()
+    }
+
+    fn format_slice(&self, slice: &[impl Display]) -> String {
+        self.render_slice(slice, Alignment::Right)
+    }
+
+    fn format_slice_aligned(&self, slice: &[impl Display], alignment: Alignment) -> String {
+        self.render_slice(slice, alignment)
+    }
+
+    fn write_slice_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        alignment: Alignment,
+    ) -> fmt::Result {
+        self.draw_slice(w, slice, alignment)
+    }
+
+    fn write_table_builder(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        table: &TableBuilder,
+    ) -> fmt::Result {
+        self.draw_table(w, table)
+    }
 
-        format!("{}\n{}{}", top_line, middle, bottom_line)
+    fn write_titled_rule(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        title: impl Display,
+        width: usize,
+    ) -> fmt::Result {
+        self.draw_rule(w, title, width)
+    }
+
+    fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> fmt::Result {
+        tree::write_tree(w, root, &label, &children, &self.tree_guides())
+    }
+
+    #[cfg(feature = "std")]
+    fn format_hash_map_headers(
+        &self,
+        map: &HashMap<impl Display, impl Display>,
+        key_header: impl AsRef<str>,
+        value_header: impl AsRef<str>,
+    ) -> String {
+        self.render_table(
+            TableBuilder::new()
+                .header([key_header.as_ref(), value_header.as_ref()])
+                .pairs(map),
+        )
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_rows<R: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        self.draw_stream(w, rows, widths)
     }
 }
@@ -0,0 +1,61 @@
+//! Declarative macros for building tables quickly, such as in scripts
+
+/// Create a row of cells, turning each value into a [`String`] using its
+/// [`Display`](core::fmt::Display) implementation
+///
+/// The values don't need to be the same type, which makes this useful for rows that mix text
+/// and numbers.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{row, styles, table::TableBuilder};
+/// let table = TableBuilder::new()
+///     .header(row!["Name", "Age"])
+///     .row(row!["Jon", 38])
+///     .row(row!["Jake", 25])
+///     .build(&styles::THIN);
+/// ```
+///
+/// [`String`]: alloc::string::String
+#[macro_export]
+macro_rules! row {
+    ($($cell:expr),* $(,)?) => {{
+        let row: $crate::__private::Vec<$crate::__private::String> =
+            $crate::__private::vec![$($crate::__private::ToString::to_string(&$cell)),*];
+        row
+    }};
+}
+
+/// Format a table with a header, where the style comes first, followed by the header and then
+/// each row, all separated by semicolons
+///
+/// Each row is written like [`row!`], so the cells don't need to be the same type.  This expands
+/// to a [`TableBuilder`](crate::table::TableBuilder) that is built with the style, and evaluates
+/// to the formatted [`String`](alloc::string::String).
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles::THIN, table};
+/// let table = table!(THIN; ["Name", "Age"]; ["Jon", 38]; ["Jake", 25]);
+///
+/// println!("{}", table);
+/// ```
+/// produces the output
+/// ```text
+/// ┌────┬───┐
+/// │Name│Age│
+/// ├────┼───┤
+/// │ Jon│ 38│
+/// ├────┼───┤
+/// │Jake│ 25│
+/// └────┴───┘
+/// ```
+#[macro_export]
+macro_rules! table {
+    ($style:expr; [$($header:expr),* $(,)?] $(; [$($cell:expr),* $(,)?])* $(;)?) => {{
+        let mut table = $crate::table::TableBuilder::new();
+        table.header($crate::row![$($header),*]);
+        $(table.row($crate::row![$($cell),*]);)*
+        table.build(&$style)
+    }};
+}
@@ -0,0 +1,274 @@
+//! Writing tables as [GitHub-flavored Markdown](https://github.github.com/gfm/#tables-extension-)
+//!
+//! [`MarkdownStyle`] implements [`BorderFormatter`], so every `format_*` method can produce a
+//! Markdown table instead of a box-drawn one:
+//! ```rust
+//! use borderrs::{BorderFormatter, styles::MARKDOWN};
+//!
+//! let markdown: String = MARKDOWN.format_table_with_headers(&["a", "b"], &[vec![1, 2]]);
+//! ```
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{
+    table::{BorderWidths, Layout, LayoutCell, TableBuilder},
+    tree::{self, Guides},
+    width, Alignment, BorderFormatter,
+};
+
+/// Format as a GitHub-flavored Markdown table
+///
+/// Markdown tables always have a header, so tables without one get a header of empty cells.
+/// Cells that span multiple lines have their lines joined with `<br>`, and any `|` in a cell is
+/// escaped so that it doesn't end the cell early.  Tables nested inside cells are written with
+/// one row per line, and trees are written as nested lists.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, table::TableBuilder, Alignment};
+/// let table = TableBuilder::new()
+///     .header(["Name", "Age", "Town"])
+///     .row(["Jon", "38", "Springfield"])
+///     .row(["Jake", "25", "Shelbyville"])
+///     .alignments(&[Alignment::Left, Alignment::Right, Alignment::Center])
+///     .build(&styles::MARKDOWN);
+///
+/// println!("{}", table);
+/// ```
+/// produces the output
+/// ```text
+/// | Name | Age |    Town     |
+/// |:-----|----:|:-----------:|
+/// | Jon  |  38 | Springfield |
+/// | Jake |  25 | Shelbyville |
+/// ```
+///
+/// Each column is aligned the same way as its cells are in the other styles, so columns found by
+/// [`TableBuilder::align_numbers`] are right-aligned and the others left-aligned:
+/// ```rust
+/// # use borderrs::{styles, table::TableBuilder};
+/// let table = TableBuilder::new()
+///     .header(["Item", "Price"])
+///     .rows([["Coffee", "3.50"], ["Bagel", "12.00"]])
+///     .align_numbers(true)
+///     .build(&styles::MARKDOWN);
+///
+/// assert_eq!(table.lines().nth(1), Some("|:-------|------:|"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarkdownStyle;
+
+impl MarkdownStyle {
+    /// Write a single row of the table, padding each cell to its column's width
+    fn write_row(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        cells: &[String],
+        widths: &[usize],
+        alignments: &[Alignment],
+    ) -> fmt::Result {
+        w.write_char('|')?;
+        for (col, &width) in widths.iter().enumerate() {
+            let text = cells.get(col).map(String::as_str).unwrap_or("");
+            let alignment = alignments.get(col).copied().unwrap_or_default();
+            w.write_char(' ')?;
+            alignment.write_aligned(w, text, width)?;
+            w.write_str(" |")?;
+        }
+        Ok(())
+    }
+
+    /// Write the line between the header and the body, which sets the alignment of each column
+    fn write_delimiter_row(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        widths: &[usize],
+        alignments: &[Alignment],
+    ) -> fmt::Result {
+        w.write_char('|')?;
+        for (col, &width) in widths.iter().enumerate() {
+            // Cover the padding on either side of the cell as well
+            let (left, right) = match alignments.get(col).copied().unwrap_or_default() {
+                Alignment::Left => (':', '-'),
+                Alignment::Right => ('-', ':'),
+                Alignment::Center => (':', ':'),
+            };
+            w.write_char(left)?;
+            for _ in 0..width {
+                w.write_char('-')?;
+            }
+            w.write_char(right)?;
+            w.write_char('|')?;
+        }
+        Ok(())
+    }
+}
+
+/// Join the lines of a cell with `<br>` and escape anything that would break the table
+///
+/// Markdown tables can't be nested, so a table inside the cell has each of its rows written on a
+/// line of their own with the cells separated by commas.
+fn cell_text(cell: &LayoutCell) -> String {
+    let Some(nested) = &cell.nested else {
+        return cell
+            .lines
+            .iter()
+            .map(|line| line.replace('|', "\\|"))
+            .collect::<Vec<_>>()
+            .join("<br>");
+    };
+    nested
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter(|cell| cell.span > 0 && !cell.continued)
+                .map(cell_text)
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+impl BorderFormatter for MarkdownStyle {
+    fn border_widths(&self) -> BorderWidths {
+        // `| ` and ` |` on the edges and ` | ` between each column
+        BorderWidths { edges: 4, inner: 3 }
+    }
+
+    fn write_slice_aligned(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        slice: &[impl Display],
+        alignment: Alignment,
+    ) -> fmt::Result {
+        TableBuilder::new()
+            .row(slice)
+            .alignments(&vec![alignment; slice.len()])
+            .write(w, self)
+    }
+
+    fn write_table_builder(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        table: &TableBuilder,
+    ) -> fmt::Result {
+        let Layout {
+            rows, has_header, ..
+        } = table.layout(self.border_widths());
+
+        // Markdown only has an alignment for each column, which is taken from the first cell of
+        // the body that is only in that column (falling back to the header), since the layout has
+        // already applied `align_numbers` and the alignment of the column to those
+        let first_body_row = usize::from(has_header);
+        let column_alignment = |col: usize| {
+            let body = rows[first_body_row..].iter().chain(&rows[..first_body_row]);
+            body.filter_map(|row| row.get(col))
+                .find(|cell| cell.span == 1 && !cell.continued)
+                .map(|cell| cell.alignment)
+                .or_else(|| table.alignments.get(col).copied())
+                .unwrap_or_default()
+        };
+        // The cells of the header are padded using their own alignment
+        let header_alignments: Vec<_> = match has_header {
+            true => rows[0].iter().map(|cell| cell.alignment).collect(),
+            false => Vec::new(),
+        };
+
+        let mut rows: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| row.iter().map(cell_text).collect())
+            .collect();
+        if !has_header {
+            rows.insert(0, Vec::new());
+        }
+
+        // The delimiter row needs at least one `-` in each column
+        let cols = rows.iter().map(Vec::len).max().unwrap_or(0).max(1);
+        let widths: Vec<_> = (0..cols)
+            .map(|col| {
+                rows.iter()
+                    .filter_map(|row| row.get(col))
+                    .map(|cell| width::display_width(cell))
+                    .max()
+                    .unwrap_or(0)
+                    .max(1)
+            })
+            .collect();
+        let alignments: Vec<_> = (0..cols).map(column_alignment).collect();
+
+        if let Some(title) = &table.title {
+            writeln!(w, "**{}**", title)?;
+            w.write_char('\n')?;
+        }
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                w.write_char('\n')?;
+            }
+            match i {
+                0 if has_header => self.write_row(w, row, &widths, &header_alignments)?,
+                _ => self.write_row(w, row, &widths, &alignments)?,
+            }
+            if i == 0 {
+                w.write_char('\n')?;
+                self.write_delimiter_row(w, &widths, &alignments)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_tree_with<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        root: &'a T,
+        label: impl Fn(&'a T) -> L,
+        children: impl Fn(&'a T) -> C,
+    ) -> fmt::Result {
+        // The root is a paragraph that the list of its descendants follows
+        let guides = Guides::new("- ", "- ", "  ", "  ");
+        tree::write_tree(w, root, &label, &children, &guides)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_rows<R: IntoIterator<Item = impl Display>>(
+        &self,
+        w: &mut (impl io::Write + ?Sized),
+        rows: impl IntoIterator<Item = R>,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        // The header is empty, so it can be written before any of the rows arrive.  As with
+        // `write_table_builder`, the delimiter row needs at least one `-` in each column
+        let widths: Vec<_> = widths.iter().map(|&width| width.max(1)).collect();
+        let head = crate::write_to_string(|out| {
+            self.write_row(out, &[], &widths, &[])?;
+            out.push('\n');
+            self.write_delimiter_row(out, &widths, &[])
+        });
+        writeln!(w, "{}", head)?;
+        w.flush()?;
+
+        for row in rows {
+            let mut table = TableBuilder::new();
+            table.row(row.into_iter().take(widths.len()));
+            for (column, &width) in widths.iter().enumerate() {
+                table.column_max_width(column, width);
+            }
+            let cells: Vec<_> = table
+                .layout(self.border_widths())
+                .rows
+                .iter()
+                .flatten()
+                .map(cell_text)
+                .collect();
+
+            let line = crate::write_to_string(|out| self.write_row(out, &cells, &widths, &[]));
+            writeln!(w, "{}", line)?;
+            w.flush()?;
+        }
+        Ok(())
+    }
+}
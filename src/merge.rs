@@ -0,0 +1,172 @@
+//! This module hosts [`merge_vertical`] and [`merge_horizontal`], which join two tables that have
+//! already been formatted so that they share the border between them.
+//!
+//! Where the lines of the two borders meet, they are fused into the matching junction, so the
+//! bottom corners of one table and the top corners of the next become `├` and `┤`, and the
+//! junctions of both tables are kept:
+//! ```rust
+//! use borderrs::{merge::merge_vertical, styles::THIN, BorderFormatter};
+//!
+//! let summary = THIN.format_slice(&["total", "63"]);
+//! let people = THIN.format_table(&[vec!["Jon", "38"], vec!["Jake", "25"]]);
+//! println!("{}", merge_vertical(&summary, &people));
+//! ```
+//! produces the output
+//! ```text
+//! ┌─────┬─────┐
+//! │total│   63│
+//! ├────┬┴─┬───┘
+//! │ Jon│38│
+//! ├────┼──┤
+//! │Jake│25│
+//! └────┴──┘
+//! ```
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt::Display, iter};
+
+use crate::{
+    junction::{self, Weight},
+    width::{self, Segment},
+};
+
+/// Stack `top` on top of `bottom`, fusing the last line of `top` with the first line of `bottom`
+///
+/// The tables don't need to be the same width.  The shared line is assumed to be made of
+/// characters that are one column wide, as borders are, and any ANSI escape sequences on it are
+/// removed.
+///
+/// See [the module docs](self) for an example.
+pub fn merge_vertical(top: impl Display, bottom: impl Display) -> String {
+    let top = top.to_string();
+    let bottom = bottom.to_string();
+    let mut lines: Vec<String> = top.lines().map(ToString::to_string).collect();
+    let mut below = bottom.lines();
+    match (lines.pop(), below.next()) {
+        (Some(above), Some(below)) => {
+            let above: Vec<_> = width::strip_ansi(above.as_str()).chars().collect();
+            let below: Vec<_> = width::strip_ansi(below).chars().collect();
+            let len = above.len().max(below.len());
+            let at = |line: &[char], i: usize| line.get(i).copied().unwrap_or(' ');
+            lines.push(
+                (0..len)
+                    .map(|i| fuse(at(&above, i), at(&below, i)))
+                    .collect(),
+            );
+        }
+        (line, None) => lines.extend(line),
+        (None, Some(line)) => lines.push(line.to_string()),
+    }
+    lines.extend(below.map(ToString::to_string));
+    lines.join("\n")
+}
+
+/// Put `left` to the left of `right`, fusing the last column of `left` with the first column of
+/// `right`
+///
+/// The tables don't need to be the same height, and lines of `left` that are narrower than the
+/// widest one are padded with spaces.  Colors are kept, but the fused column is drawn in the
+/// color of `left`.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{merge::merge_horizontal, styles::THIN, BorderFormatter};
+/// let names = THIN.format_slice_vertical(&["Jon", "Jake", "Josh"]);
+/// let ages = THIN.format_slice_vertical(&[38, 25]);
+/// println!("{}", merge_horizontal(&names, &ages));
+/// ```
+/// produces the output
+/// ```text
+/// ┌────┬──┐
+/// │ Jon│38│
+/// ├────┼──┤
+/// │Jake│25│
+/// ├────┼──┘
+/// │Josh│
+/// └────┘
+/// ```
+pub fn merge_horizontal(left: impl Display, right: impl Display) -> String {
+    let left = left.to_string();
+    let right = right.to_string();
+    let left: Vec<_> = left.lines().collect();
+    let right: Vec<_> = right.lines().collect();
+    let width = left
+        .iter()
+        .map(|line| width::display_width(line))
+        .max()
+        .unwrap_or(0);
+    if width == 0 {
+        return right.join("\n");
+    }
+
+    let height = left.len().max(right.len());
+    let lines: Vec<String> = (0..height)
+        .map(|i| {
+            let mut line = left.get(i).copied().unwrap_or("").to_string();
+            let padding = width - width::display_width(&line);
+            line.extend(iter::repeat_n(' ', padding));
+            let (head, last) = width::split_at_width(&line, width - 1);
+            let (first, rest) = width::split_at_width(right.get(i).copied().unwrap_or(""), 1);
+
+            let c = fuse(visible(last), visible(first));
+            let mut out = head.to_string();
+            for segment in width::segments(last) {
+                match segment {
+                    Segment::Escape(escape) => out.push_str(escape),
+                    Segment::Text(_) => out.push(c),
+                }
+            }
+            // Keep any colors that start before the first column of `right`
+            for segment in width::segments(first) {
+                if let Segment::Escape(escape) = segment {
+                    out.push_str(escape);
+                }
+            }
+            out.push_str(rest);
+            out
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Get the first visible character of `text`, or a space if there isn't one
+fn visible(text: &str) -> char {
+    width::strip_ansi(text).chars().next().unwrap_or(' ')
+}
+
+/// Fuse two characters that are drawn in the same place into one
+///
+/// Box-drawing characters are joined into the junction that has the lines of both, keeping the
+/// weight of `a` where they both have a line, or using the weight of `a` for every line if there
+/// isn't a junction for that mix of weights.  ASCII borders are joined into `+`.  Anything else
+/// is kept as `a`, unless it is a space.
+fn fuse(a: char, b: char) -> char {
+    const ASCII: &str = "+-|=";
+    match (a, b) {
+        (a, ' ') => a,
+        (' ', b) => b,
+        (a, b) if a == b => a,
+        (a, b) if ASCII.contains(a) && ASCII.contains(b) => '+',
+        (a, b) => {
+            let (Some(a_arms), Some(b_arms)) = (junction::arms(a), junction::arms(b)) else {
+                return a;
+            };
+            let arms: [Weight; 4] = core::array::from_fn(|i| match a_arms[i] {
+                Weight::Empty => b_arms[i],
+                weight => weight,
+            });
+            // There aren't junctions for every mix of weights, such as double and thin lines
+            // meeting at a corner, so the lines of `b` are drawn like the lines of `a` instead
+            let weight = a_arms.into_iter().find(|&weight| weight != Weight::Empty);
+            let uniform = arms.map(|arm| match (arm, weight) {
+                (Weight::Empty, _) | (_, None) => arm,
+                (_, Some(weight)) => weight,
+            });
+            junction::junction(arms)
+                .or_else(|| junction::junction(uniform))
+                .unwrap_or(a)
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! This module hosts the [`Panel`], which is used to draw a box around a message with a title.
+//!
+//! The intended usage is as follows:
+//! ```rust
+//! use borderrs::{panel::Panel, styles::ROUNDED};
+//!
+//! let panel = Panel::new("The build finished without any warnings.")
+//!     .title("Success")
+//!     .padding(1, 0)
+//!     .build(&ROUNDED);
+//! ```
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display},
+    iter,
+};
+
+use crate::{
+    table::{BorderWidths, TableBuilder},
+    width, write_to_string, Alignment, BorderFormatter,
+};
+
+/// Used to draw a box around a message, like a message box, with an optional title inset into its
+/// top border
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{panel::Panel, styles, Alignment};
+/// let panel = Panel::new("Disk space is running low.\nFree up some space to keep working.")
+///     .title("Warning")
+///     .title_alignment(Alignment::Center)
+///     .alignment(Alignment::Center)
+///     .padding(2, 1)
+///     .build(&styles::THIN);
+///
+/// println!("{}", panel);
+/// ```
+/// produces the output
+/// ```text
+/// ┌─────────────── Warning ───────────────┐
+/// │                                       │
+/// │      Disk space is running low.       │
+/// │  Free up some space to keep working.  │
+/// │                                       │
+/// └───────────────────────────────────────┘
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Panel {
+    /// The text inside of the panel
+    body: String,
+    /// The title inset into the top border
+    title: Option<String>,
+    /// Where the title is placed along the top border, defaulting to the left
+    title_alignment: Option<Alignment>,
+    /// How each line of the body is aligned, defaulting to the left
+    alignment: Option<Alignment>,
+    /// The number of spaces on either side of the body
+    horizontal_padding: usize,
+    /// The number of blank lines above and below the body
+    vertical_padding: usize,
+    /// The width of the whole panel, including its borders
+    width: Option<usize>,
+    /// How words that are too long for a line of their own are broken up
+    word_break: WordBreak,
+}
+
+/// Controls how words that are too long to fit on a line of their own are broken up when the
+/// text is wrapped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WordBreak {
+    /// Break the word wherever the line ends
+    #[default]
+    Break,
+    /// Break the word one column earlier and end the line with a `-`
+    Hyphenate,
+}
+
+impl Panel {
+    /// Create a panel around `body` without a title or any padding
+    pub fn new(body: impl Display) -> Self {
+        Self {
+            body: body.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the title inset into the top border of the panel
+    pub fn title(&mut self, title: impl Display) -> &mut Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Set where the title is placed along the top border, defaults to [`Alignment::Left`]
+    pub fn title_alignment(&mut self, alignment: Alignment) -> &mut Self {
+        self.title_alignment = Some(alignment);
+        self
+    }
+
+    /// Set how each line of the body is aligned, defaults to [`Alignment::Left`]
+    pub fn alignment(&mut self, alignment: Alignment) -> &mut Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Put `horizontal` spaces on either side of the body and `vertical` blank lines above and
+    /// below it
+    pub fn padding(&mut self, horizontal: usize, vertical: usize) -> &mut Self {
+        self.horizontal_padding = horizontal;
+        self.vertical_padding = vertical;
+        self
+    }
+
+    /// Make the whole panel, including its borders, `width` columns wide
+    ///
+    /// Lines of the body that are too wide are wrapped, breaking between words where possible.
+    /// The panel is only wider if its title doesn't fit.  The borders take up as many columns as
+    /// they do in the style the panel is drawn with.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{panel::Panel, StringBorderStyle};
+    /// let panel = Panel::new("hello there world")
+    ///     .width(12)
+    ///     .build(&StringBorderStyle::new("||", "=", "++"));
+    ///
+    /// assert_eq!(panel, "++========++\n||hello   ||\n||there   ||\n||world   ||\n++========++");
+    /// ```
+    pub fn width(&mut self, width: usize) -> &mut Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set how words that are too long to fit on a line of their own are broken up when the body
+    /// is wrapped, defaults to [`WordBreak::Break`]
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{panel::{Panel, WordBreak}, styles};
+    /// let panel = Panel::new("See https://github.com/funnyboy-roks/borders")
+    ///     .width(16)
+    ///     .word_break(WordBreak::Hyphenate)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", panel);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌──────────────┐
+    /// │See           │
+    /// │https://githu-│
+    /// │b.com/funnybo-│
+    /// │y-roks/borders│
+    /// └──────────────┘
+    /// ```
+    pub fn word_break(&mut self, word_break: WordBreak) -> &mut Self {
+        self.word_break = word_break;
+        self
+    }
+
+    /// Format the panel using `style`
+    pub fn build(&self, style: &impl BorderFormatter) -> String {
+        write_to_string(|w| self.write(w, style))
+    }
+
+    /// Write the panel into `w` using `style`
+    pub fn write(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        style: &impl BorderFormatter,
+    ) -> fmt::Result {
+        style.write_table_builder(w, &self.table(style.border_widths()))
+    }
+
+    /// Build the single-cell table that the panel is drawn as, for a style whose borders are as
+    /// wide as `borders`
+    pub(crate) fn table(&self, borders: BorderWidths) -> TableBuilder {
+        let padding = 2 * self.horizontal_padding;
+        let content = self
+            .width
+            .map(|width| width.saturating_sub(borders.edges + padding));
+        let hyphen = match self.word_break {
+            WordBreak::Break => "",
+            WordBreak::Hyphenate => "-",
+        };
+        let lines: Vec<_> = match content {
+            Some(content) => self
+                .body
+                .lines()
+                .flat_map(|line| width::wrap_with_hyphen(line, content, hyphen))
+                .collect(),
+            None => self.body.lines().map(String::from).collect(),
+        };
+        let content = content.unwrap_or_else(|| {
+            lines
+                .iter()
+                .map(|line| width::display_width(line))
+                .max()
+                .unwrap_or(0)
+        });
+
+        // Put the padding on either side of each line, and let the table align the lines so that
+        // they stay aligned when a long title makes the panel wider
+        let side = " ".repeat(self.horizontal_padding);
+        let body = lines.iter().map(|line| format!("{}{}{}", side, line, side));
+        // The blank lines are filled with spaces so that the last ones aren't dropped
+        let vertical = iter::repeat_n(" ".repeat(content + padding), self.vertical_padding);
+        let text: Vec<_> = vertical.clone().chain(body).chain(vertical).collect();
+
+        let mut table = TableBuilder::new();
+        table
+            .row([text.join("\n")])
+            .alignments(&[self.alignment.unwrap_or(Alignment::Left)])
+            .min_width(content + padding);
+        if let Some(title) = &self.title {
+            table.title(title);
+        }
+        if let Some(alignment) = self.title_alignment {
+            table.title_alignment(alignment);
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles;
+
+    #[test]
+    fn alignment_survives_a_wide_title() {
+        let panel = Panel::new("hi")
+            .title("A very long title")
+            .alignment(Alignment::Center)
+            .build(&styles::THIN);
+        assert_eq!(panel.lines().nth(1), Some("│         hi          │"));
+    }
+}
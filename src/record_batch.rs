@@ -0,0 +1,67 @@
+//! Building tables from Arrow [`RecordBatch`]es for [`BorderFormatter::format_record_batch`]
+//!
+//! Requires the `arrow` feature.
+//!
+//! [`BorderFormatter::format_record_batch`]: crate::BorderFormatter::format_record_batch
+use arrow_array::RecordBatch;
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, DataType};
+
+use crate::{
+    table::{Cell, TableBuilder},
+    Alignment,
+};
+
+/// Build a table from `batch`, with a column for each of its fields
+///
+/// If the batch has more than `max_rows` rows, only its first and last rows are kept, with a row
+/// of `…` between them.  Nulls are left empty.
+pub(crate) fn table(batch: &RecordBatch, max_rows: usize) -> Result<TableBuilder, ArrowError> {
+    let options = FormatOptions::default().with_null("");
+    let formatters = batch
+        .columns()
+        .iter()
+        .map(|column| ArrayFormatter::try_new(column, &options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let schema = batch.schema();
+    let mut table = TableBuilder::new();
+    table
+        .header(schema.fields().iter().map(|field| field.name()))
+        .alignments(
+            &schema
+                .fields()
+                .iter()
+                .map(|field| alignment(field.data_type()))
+                .collect::<Vec<_>>(),
+        );
+
+    let rows = batch.num_rows();
+    let (head, tail) = match rows > max_rows {
+        true => (max_rows.div_ceil(2), max_rows / 2),
+        false => (rows, 0),
+    };
+    for row in 0..head {
+        table.row(formatters.iter().map(|formatter| formatter.value(row)));
+    }
+    if head + tail < rows {
+        table.styled_row(
+            formatters
+                .iter()
+                .map(|_| Cell::new("…").with_alignment(Alignment::Center)),
+        );
+    }
+    for row in rows - tail..rows {
+        table.row(formatters.iter().map(|formatter| formatter.value(row)));
+    }
+    Ok(table)
+}
+
+/// Get the alignment of a column of `data_type`, with numbers on the right and everything else
+/// on the left
+fn alignment(data_type: &DataType) -> Alignment {
+    match data_type.is_numeric() {
+        true => Alignment::Right,
+        false => Alignment::Left,
+    }
+}
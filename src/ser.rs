@@ -0,0 +1,446 @@
+//! This module hosts the [`serde`] integration used by [`BorderFormatter::format_serialize`].
+//!
+//! Values are first serialized into a small tree of scalars, sequences, and maps, which is then
+//! rendered into nested tables:
+//! - scalars (numbers, strings, unit variants, ...) are displayed as-is
+//! - sequences (`Vec`s, tuples, ...) become a table with one entry per row
+//! - maps and structs become a two-column key/value table
+//!
+//! Requires the `serde` feature.
+//!
+//! [`BorderFormatter::format_serialize`]: crate::BorderFormatter::format_serialize
+use std::fmt::{self, Display};
+
+use serde::ser::{self, Serialize};
+
+use crate::{
+    table::{Cell, TableBuilder},
+    Alignment,
+};
+
+/// An error that occurred while formatting a [`Serialize`] value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Self("an error occurred while writing the table".into())
+    }
+}
+
+/// The shape of a serialized value, before it is rendered
+pub(crate) enum Node {
+    /// A value without any structure, already formatted
+    Scalar(String),
+    /// A list of values
+    Seq(Vec<Node>),
+    /// A list of key/value pairs, with the keys already formatted
+    Map(Vec<(String, Node)>),
+}
+
+impl Node {
+    /// Serialize `value` into a [`Node`]
+    pub(crate) fn from_serialize(value: &(impl Serialize + ?Sized)) -> Result<Self, Error> {
+        value.serialize(NodeSerializer)
+    }
+
+    /// Wrap this node in a single-entry map, used for enum variants that contain data
+    fn in_variant(self, variant: Option<&'static str>) -> Self {
+        match variant {
+            Some(variant) => Node::Map(vec![(variant.to_string(), self)]),
+            None => self,
+        }
+    }
+
+    /// Build the table for this node, with nested tables for sequences and maps, or `None` if it
+    /// is a scalar or empty
+    ///
+    /// The values are left-aligned so that nested tables line up with the left edge of their
+    /// cell.
+    pub(crate) fn table(&self) -> Option<TableBuilder> {
+        let mut table = TableBuilder::new();
+        match self {
+            Node::Seq(items) if !items.is_empty() => {
+                table.alignments(&[Alignment::Left]);
+                for item in items {
+                    table.styled_row([item.cell()]);
+                }
+            }
+            Node::Map(entries) if !entries.is_empty() => {
+                table.alignments(&[Alignment::Right, Alignment::Left]);
+                for (key, value) in entries {
+                    table.styled_row([Cell::new(key), value.cell()]);
+                }
+            }
+            _ => return None,
+        }
+        Some(table)
+    }
+
+    /// Make a cell for this node, with a nested table if it is a sequence or map
+    fn cell(&self) -> Cell {
+        match self {
+            Node::Scalar(s) => Cell::new(s),
+            node => node.table().map_or_else(Cell::default, Cell::table),
+        }
+    }
+}
+
+/// A [`ser::Serializer`] that turns any value into a [`Node`]
+struct NodeSerializer;
+
+/// Builds a [`Node::Seq`] for sequences, tuples, and tuple variants
+struct SeqBuilder {
+    /// The items that have been serialized so far
+    items: Vec<Node>,
+    /// The name of the variant, if this is a tuple variant
+    variant: Option<&'static str>,
+}
+
+/// Builds a [`Node::Map`] for maps, structs, and struct variants
+struct MapBuilder {
+    /// The entries that have been serialized so far
+    entries: Vec<(String, Node)>,
+    /// The key that is waiting for its value, when serializing a map
+    key: Option<String>,
+    /// The name of the variant, if this is a struct variant
+    variant: Option<&'static str>,
+}
+
+impl NodeSerializer {
+    /// Serialize a map key, which is rendered right away since keys are usually scalars
+    fn key(key: &(impl Serialize + ?Sized)) -> Result<String, Error> {
+        match Node::from_serialize(key)? {
+            Node::Scalar(s) => Ok(s),
+            _ => Err(Error("map keys must be scalar values".into())),
+        }
+    }
+
+    /// Create a scalar node using the value's [`Display`] implementation
+    fn scalar(value: impl Display) -> Result<Node, Error> {
+        Ok(Node::Scalar(value.to_string()))
+    }
+}
+
+impl ser::Serializer for NodeSerializer {
+    type Ok = Node;
+    type Error = Error;
+    type SerializeSeq = SeqBuilder;
+    type SerializeTuple = SeqBuilder;
+    type SerializeTupleStruct = SeqBuilder;
+    type SerializeTupleVariant = SeqBuilder;
+    type SerializeMap = MapBuilder;
+    type SerializeStruct = MapBuilder;
+    type SerializeStructVariant = MapBuilder;
+
+    fn serialize_bool(self, v: bool) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Node, Error> {
+        Self::scalar(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Node, Error> {
+        Ok(Node::Seq(
+            v.iter().map(|b| Node::Scalar(b.to_string())).collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Node, Error> {
+        Self::scalar("")
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Node, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Node, Error> {
+        Self::scalar("")
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Node, Error> {
+        Self::scalar(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Node, Error> {
+        Self::scalar(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Node, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Node, Error> {
+        Ok(value.serialize(self)?.in_variant(Some(variant)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqBuilder, Error> {
+        Ok(SeqBuilder {
+            items: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqBuilder, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqBuilder, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqBuilder, Error> {
+        Ok(SeqBuilder {
+            items: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapBuilder, Error> {
+        Ok(MapBuilder {
+            entries: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapBuilder, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<MapBuilder, Error> {
+        Ok(MapBuilder {
+            entries: Vec::with_capacity(len),
+            key: None,
+            variant: Some(variant),
+        })
+    }
+}
+
+impl SeqBuilder {
+    /// Add an item to the end of the sequence
+    fn push(&mut self, value: &(impl Serialize + ?Sized)) -> Result<(), Error> {
+        self.items.push(Node::from_serialize(value)?);
+        Ok(())
+    }
+
+    /// Finish the sequence, wrapping it in its variant if needed
+    fn finish(self) -> Result<Node, Error> {
+        Ok(Node::Seq(self.items).in_variant(self.variant))
+    }
+}
+
+impl ser::SerializeSeq for SeqBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl MapBuilder {
+    /// Add an entry to the end of the map
+    fn insert(&mut self, key: String, value: &(impl Serialize + ?Sized)) -> Result<(), Error> {
+        self.entries.push((key, Node::from_serialize(value)?));
+        Ok(())
+    }
+
+    /// Finish the map, wrapping it in its variant if needed
+    fn finish(self) -> Result<Node, Error> {
+        Ok(Node::Map(self.entries).in_variant(self.variant))
+    }
+}
+
+impl ser::SerializeMap for MapBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(NodeSerializer::key(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error("serialize_value called before serialize_key".into()))?;
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for MapBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for MapBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
@@ -0,0 +1,58 @@
+//! Building [`TableBuilder`]s from the result of an SQLite query for
+//! [`BorderFormatter::format_sqlite_rows`]
+//!
+//! Requires the `sqlite` feature.
+//!
+//! [`BorderFormatter::format_sqlite_rows`]: crate::BorderFormatter::format_sqlite_rows
+use core::fmt::Write;
+
+use rusqlite::{types::ValueRef, Rows};
+
+use crate::table::TableBuilder;
+
+/// Read every row of a query into a table, using the names of its columns as the header
+///
+/// `NULL`s are left empty, text is drawn as it is (replacing any invalid UTF-8), and blobs are
+/// drawn as SQL hex literals, such as `X'CAFE'`.
+///
+/// See [`BorderFormatter::format_sqlite_rows`](crate::BorderFormatter::format_sqlite_rows) for
+/// example
+impl TryFrom<Rows<'_>> for TableBuilder {
+    type Error = rusqlite::Error;
+
+    fn try_from(mut rows: Rows<'_>) -> Result<Self, Self::Error> {
+        let mut table = TableBuilder::new();
+        let columns = match rows.as_ref() {
+            Some(statement) => {
+                table.header(statement.column_names());
+                statement.column_count()
+            }
+            None => 0,
+        };
+        while let Some(row) = rows.next()? {
+            let cells = (0..columns)
+                .map(|column| row.get_ref(column).map(text))
+                .collect::<Result<Vec<_>, _>>()?;
+            table.row(cells);
+        }
+        Ok(table)
+    }
+}
+
+/// Get the text that `value` is drawn as
+fn text(value: ValueRef<'_>) -> String {
+    match value {
+        ValueRef::Null => String::new(),
+        ValueRef::Integer(i) => i.to_string(),
+        ValueRef::Real(f) => f.to_string(),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned(),
+        ValueRef::Blob(blob) => {
+            let mut out = String::from("X'");
+            for byte in blob {
+                write!(out, "{:02X}", byte).expect("writing to a String can't fail");
+            }
+            out.push('\'');
+            out
+        }
+    }
+}
@@ -1,12 +1,14 @@
 //! This module hosts the built-in styles.
 //!
-//! The inteded usage is as follows:
+//! The intended usage is as follows:
 //! ```rust
-//! use borders::{BorderFormatter, stypes::THIN};
+//! use borderrs::{BorderFormatter, styles::THIN};
 //!
 //! let display: String = THIN.format_display("Hello World!");
 //! ```
-use crate::SimpleBorderStyle;
+use crate::{
+    accessible::AccessibleStyle, html::HtmlStyle, markdown::MarkdownStyle, SimpleBorderStyle,
+};
 
 /// Format with a single thin line
 ///
@@ -21,6 +23,10 @@ pub const THIN: SimpleBorderStyle = SimpleBorderStyle {
     vertical: '│',
     horizontal: '─',
 
+    inner_vertical: '│',
+    inner_horizontal: '─',
+    header_horizontal: '─',
+
     horizontal_up: '┴',
     horizontal_down: '┬',
 
@@ -34,6 +40,8 @@ pub const THIN: SimpleBorderStyle = SimpleBorderStyle {
     bottom_right: '┘',
 
     cross: '┼',
+
+    border_color: None,
 };
 
 /// Format with a double line
@@ -49,6 +57,10 @@ pub const DOUBLE: SimpleBorderStyle = SimpleBorderStyle {
     vertical: '║',
     horizontal: '═',
 
+    inner_vertical: '║',
+    inner_horizontal: '═',
+    header_horizontal: '═',
+
     horizontal_up: '╩',
     horizontal_down: '╦',
 
@@ -62,6 +74,110 @@ pub const DOUBLE: SimpleBorderStyle = SimpleBorderStyle {
     bottom_right: '╝',
 
     cross: '╬',
+
+    border_color: None,
+};
+
+/// Format with a single thin line and rounded corners
+///
+/// ```text
+/// ╭───┬───╮
+/// │   │   │
+/// ├───┼───┤
+/// │   │   │
+/// ╰───┴───╯
+/// ```
+pub const ROUNDED: SimpleBorderStyle = SimpleBorderStyle {
+    vertical: '│',
+    horizontal: '─',
+
+    inner_vertical: '│',
+    inner_horizontal: '─',
+    header_horizontal: '─',
+
+    horizontal_up: '┴',
+    horizontal_down: '┬',
+
+    vertical_right: '├',
+    vertical_left: '┤',
+
+    top_left: '╭',
+    top_right: '╮',
+
+    bottom_left: '╰',
+    bottom_right: '╯',
+
+    cross: '┼',
+
+    border_color: None,
+};
+
+/// Format with a single thick line
+///
+/// ```text
+/// ┏━━━┳━━━┓
+/// ┃   ┃   ┃
+/// ┣━━━╋━━━┫
+/// ┃   ┃   ┃
+/// ┗━━━┻━━━┛
+/// ```
+pub const THICK: SimpleBorderStyle = SimpleBorderStyle {
+    vertical: '┃',
+    horizontal: '━',
+
+    inner_vertical: '┃',
+    inner_horizontal: '━',
+    header_horizontal: '━',
+
+    horizontal_up: '┻',
+    horizontal_down: '┳',
+
+    vertical_right: '┣',
+    vertical_left: '┫',
+
+    top_left: '┏',
+    top_right: '┓',
+
+    bottom_left: '┗',
+    bottom_right: '┛',
+
+    cross: '╋',
+
+    border_color: None,
+};
+
+/// Format with a double line around the outside and a single thin line inside
+///
+/// ```text
+/// ╔═══╤═══╗
+/// ║   │   ║
+/// ╟───┼───╢
+/// ║   │   ║
+/// ╚═══╧═══╝
+/// ```
+pub const DOUBLE_OUTER_THIN_INNER: SimpleBorderStyle = SimpleBorderStyle {
+    vertical: '║',
+    horizontal: '═',
+
+    inner_vertical: '│',
+    inner_horizontal: '─',
+    header_horizontal: '─',
+
+    horizontal_up: '╧',
+    horizontal_down: '╤',
+
+    vertical_right: '╟',
+    vertical_left: '╢',
+
+    top_left: '╔',
+    top_right: '╗',
+
+    bottom_left: '╚',
+    bottom_right: '╝',
+
+    cross: '┼',
+
+    border_color: None,
 };
 
 /// Format using only ASCII characters (`+`, `-`, `|`)
@@ -77,6 +193,74 @@ pub const ASCII: SimpleBorderStyle = SimpleBorderStyle {
     vertical: '|',
     horizontal: '-',
 
+    inner_vertical: '|',
+    inner_horizontal: '-',
+    header_horizontal: '-',
+
+    horizontal_up: '+',
+    horizontal_down: '+',
+
+    vertical_right: '+',
+    vertical_left: '+',
+
+    top_left: '+',
+    top_right: '+',
+
+    bottom_left: '+',
+    bottom_right: '+',
+
+    cross: '+',
+
+    border_color: None,
+};
+
+/// Format as a GitHub-flavored Markdown table, see [`MarkdownStyle`]
+///
+/// ```text
+/// |   |   |
+/// |--:|--:|
+/// |   |   |
+/// ```
+pub const MARKDOWN: MarkdownStyle = MarkdownStyle;
+
+/// Format as an HTML `<table>` without any classes, see [`HtmlStyle`]
+///
+/// ```text
+/// <table>
+///   <tbody>
+///     <tr><td style="text-align: right"></td><td style="text-align: right"></td></tr>
+///   </tbody>
+/// </table>
+/// ```
+pub const HTML: HtmlStyle = HtmlStyle::new();
+
+/// Format as plain `Header: value` lines for screen readers, see [`AccessibleStyle`]
+///
+/// ```text
+/// Header: value
+/// Header: value
+/// ```
+pub const ACCESSIBLE: AccessibleStyle = AccessibleStyle;
+
+/// Format using only ASCII characters, with a `=` line under the header
+///
+/// ```text
+/// +---+---+
+/// |   |   |
+/// +===+===+
+/// |   |   |
+/// +---+---+
+/// |   |   |
+/// +---+---+
+/// ```
+pub const ASCII_HEADER: SimpleBorderStyle = SimpleBorderStyle {
+    vertical: '|',
+    horizontal: '-',
+
+    inner_vertical: '|',
+    inner_horizontal: '-',
+    header_horizontal: '=',
+
     horizontal_up: '+',
     horizontal_down: '+',
 
@@ -90,4 +274,133 @@ pub const ASCII: SimpleBorderStyle = SimpleBorderStyle {
     bottom_right: '+',
 
     cross: '+',
+
+    border_color: None,
+};
+
+/// Format without any visible borders, using spaces in place of every line
+///
+/// The columns are still padded and aligned, which is useful for plain output such as logs where
+/// box-drawing characters aren't wanted.
+///
+/// ```text
+///           
+///  Name Age
+///           
+///  Jon   38
+///           
+/// ```
+pub const BLANK: SimpleBorderStyle = SimpleBorderStyle {
+    vertical: ' ',
+    horizontal: ' ',
+
+    inner_vertical: ' ',
+    inner_horizontal: ' ',
+    header_horizontal: ' ',
+
+    horizontal_up: ' ',
+    horizontal_down: ' ',
+
+    vertical_right: ' ',
+    vertical_left: ' ',
+
+    top_left: ' ',
+    top_right: ' ',
+
+    bottom_left: ' ',
+    bottom_right: ' ',
+
+    cross: ' ',
+
+    border_color: None,
 };
+
+/// Every built-in [`SimpleBorderStyle`], along with the name it is looked up by
+static NAMED: [(&str, &SimpleBorderStyle); 8] = [
+    ("thin", &THIN),
+    ("double", &DOUBLE),
+    ("rounded", &ROUNDED),
+    ("thick", &THICK),
+    ("double_outer_thin_inner", &DOUBLE_OUTER_THIN_INNER),
+    ("ascii", &ASCII),
+    ("ascii_header", &ASCII_HEADER),
+    ("blank", &BLANK),
+];
+
+/// Look up a built-in style by its name, such as from a `--border-style` flag
+///
+/// Each name is the name of the constant in lowercase, such as `"double"` for [`DOUBLE`].  Case
+/// is ignored, and `-` can be used in place of `_`.  [`MARKDOWN`] and [`HTML`] aren't
+/// [`SimpleBorderStyle`]s, so they can't be looked up.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::styles;
+/// assert_eq!(styles::by_name("double"), Some(&styles::DOUBLE));
+/// assert_eq!(styles::by_name("Ascii-Header"), Some(&styles::ASCII_HEADER));
+/// assert_eq!(styles::by_name("dotted"), None);
+/// ```
+pub fn by_name(name: &str) -> Option<&'static SimpleBorderStyle> {
+    let matches = |known: &str| {
+        known.len() == name.len()
+            && known.bytes().zip(name.bytes()).all(|(k, n)| match n {
+                b'-' => k == b'_',
+                n => k == n.to_ascii_lowercase(),
+            })
+    };
+    NAMED
+        .iter()
+        .find(|(known, _)| matches(known))
+        .map(|&(_, style)| style)
+}
+
+/// Iterate over every built-in style that can be looked up with [`by_name`], along with its name
+///
+/// For example,
+/// ```rust
+/// # use borderrs::styles;
+/// let names: Vec<_> = styles::all().map(|(name, _)| name).collect();
+/// println!("possible values: {}", names.join(", "));
+/// ```
+pub fn all() -> impl Iterator<Item = (&'static str, &'static SimpleBorderStyle)> {
+    NAMED.iter().copied()
+}
+
+/// Pick [`THIN`] if the terminal can show Unicode, or fall back to [`ASCII`] if it can't
+///
+/// The `BORDERRS_STYLE` environment variable takes priority if it names a style that can be
+/// looked up with [`by_name`], so users can always override the choice.  Otherwise, [`ASCII`]
+/// is used if `TERM` is `dumb`, or if the locale (the first of `LC_ALL`, `LC_CTYPE`, and `LANG`
+/// that is set) isn't UTF-8.  Windows consoles don't set a locale, so they get [`THIN`] unless
+/// `TERM` says otherwise.
+///
+/// Requires the `std` feature.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, BorderFormatter};
+/// println!("{}", styles::auto().format_slice(&[1, 2, 3]));
+/// ```
+#[cfg(feature = "std")]
+pub fn auto() -> &'static SimpleBorderStyle {
+    let var = |name| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+    if let Some(style) = var("BORDERRS_STYLE").and_then(|name| by_name(&name)) {
+        return style;
+    }
+    if var("TERM").is_some_and(|term| term == "dumb") {
+        return &ASCII;
+    }
+    let utf8 = match ["LC_ALL", "LC_CTYPE", "LANG"].into_iter().find_map(var) {
+        Some(locale) => {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => cfg!(windows),
+    };
+    if utf8 {
+        &THIN
+    } else {
+        &ASCII
+    }
+}
@@ -0,0 +1,3342 @@
+//! This module hosts the [`TableBuilder`], which is used to build up a table one row at a time.
+//!
+//! The intended usage is as follows:
+//! ```rust
+//! use borderrs::{styles::THIN, table::TableBuilder};
+//!
+//! let mut table = TableBuilder::new();
+//! table.header(["Name", "Age"]);
+//! for (name, age) in [("Jon", 38), ("Jake", 25)] {
+//!     table.row([name.to_string(), age.to_string()]);
+//! }
+//!
+//! let display: String = table.build(&THIN);
+//! ```
+use alloc::{
+    borrow::{Borrow, Cow},
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    iter,
+};
+
+use crate::{
+    chart,
+    color::Color,
+    error::{self, BorderError},
+    width, write_to_string, Alignment, BorderFormatter,
+};
+
+/// Derive [`ToRow`] for a struct, using the field names as headers
+///
+/// Fields can be renamed with `#[row(rename = "Header")]` or left out with `#[row(skip)]`.
+///
+/// Requires the `derive` feature.
+#[cfg(feature = "derive")]
+pub use borderrs_derive::ToRow;
+
+/// Used to build a table incrementally before formatting it with a [`BorderFormatter`]
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, table::TableBuilder, Alignment};
+/// let table = TableBuilder::new()
+///     .header(["Name", "Age"])
+///     .row(["Jon", "38"])
+///     .row(["Jake", "25"])
+///     .alignments(&[Alignment::Left])
+///     .build(&styles::THIN);
+///
+/// println!("{}", table);
+/// ```
+/// produces the output
+/// ```text
+/// ┌────┬───┐
+/// │Name│Age│
+/// ├────┼───┤
+/// │Jon │ 38│
+/// ├────┼───┤
+/// │Jake│ 25│
+/// └────┴───┘
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableBuilder {
+    /// The header row, empty if there is no header
+    pub(crate) headers: Vec<Cell>,
+    /// The rows in the body of the table
+    pub(crate) rows: Vec<Vec<Cell>>,
+    /// The footer row, empty if there is no footer
+    pub(crate) footers: Vec<Cell>,
+    /// The alignment of each column
+    pub(crate) alignments: Vec<Alignment>,
+    /// The alignment of every cell in the header, overriding the alignment of its column
+    pub(crate) header_alignment: Option<Alignment>,
+    /// Where the lines of a cell are placed in a row that is taller than the cell
+    pub(crate) vertical_alignment: VerticalAlignment,
+    /// The vertical alignment of specific columns, overriding the one of the table
+    pub(crate) column_vertical_alignments: Vec<Option<VerticalAlignment>>,
+    /// The number of blank lines above and below the lines of each row
+    pub(crate) row_padding: (usize, usize),
+    /// The character that the lines of each cell are padded with, instead of spaces
+    pub(crate) fill: Option<char>,
+    /// The fill character of specific columns, overriding the one of the table
+    pub(crate) column_fills: Vec<Option<char>>,
+    /// Which rows get a horizontal rule between them
+    pub(crate) row_separator: RowSeparator,
+    /// The title inset into the top border
+    pub(crate) title: Option<String>,
+    /// Where the title is placed along the top border, defaulting to the left
+    pub(crate) title_alignment: Option<Alignment>,
+    /// Whether every column should be as wide as the widest column
+    pub(crate) uniform_width: bool,
+    /// The maximum width of every column
+    pub(crate) max_width: Option<usize>,
+    /// The maximum width of specific columns, overriding `max_width`
+    pub(crate) column_max_widths: Vec<Option<usize>>,
+    /// The minimum width of every column
+    pub(crate) min_width: Option<usize>,
+    /// The minimum width of specific columns, overriding `min_width`
+    pub(crate) column_min_widths: Vec<Option<usize>>,
+    /// Put at the end of cells that are too wide for their column
+    pub(crate) ellipsis: Option<String>,
+    /// What to do with lines that are too wide for their column
+    pub(crate) overflow: Overflow,
+    /// The number of columns between each tab stop that tabs are expanded to, if they are
+    pub(crate) tab_width: Option<usize>,
+    /// What to do with control characters in cells
+    pub(crate) control_chars: ControlChars,
+    /// Whether lines containing right-to-left text are wrapped in bidi isolates
+    pub(crate) isolate_bidi: bool,
+    /// The total width that the table should fit within
+    pub(crate) auto_fit: Option<usize>,
+    /// The exact width of the whole table, which is shared between the columns by their weights
+    pub(crate) total_width: Option<usize>,
+    /// The share of `total_width` that specific columns get, defaulting to one
+    pub(crate) column_weights: Vec<Option<usize>>,
+    /// The background of every other row in the body
+    pub(crate) zebra: Option<Color>,
+    /// The number of rows in the body that are drawn before the rest are summarized
+    pub(crate) max_rows: Option<usize>,
+    /// What is drawn when the body of the table is empty
+    pub(crate) empty_behavior: EmptyBehavior,
+    /// Whether columns of numbers are aligned on their decimal point
+    pub(crate) align_numbers: bool,
+    /// Put between each group of three digits in columns of numbers
+    pub(crate) thousands_separator: Option<char>,
+}
+
+/// Controls what happens to lines that are wider than the maximum width of their column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Cut the line off and end it with the ellipsis
+    #[default]
+    Truncate,
+    /// Wrap the line onto multiple lines, breaking between words where possible
+    Wrap,
+}
+
+/// Controls where the lines of a cell are placed in a row that is taller than the cell, such as
+/// when another cell in the row has been wrapped onto several lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlignment {
+    /// Place the lines against the top of the row
+    #[default]
+    Top,
+    /// Place the lines in the middle of the row, leaning up if they can't be exactly centered
+    Middle,
+    /// Place the lines against the bottom of the row
+    Bottom,
+}
+
+impl VerticalAlignment {
+    /// Get the number of blank lines above `lines` lines of text in a row that is `height` lines
+    /// tall
+    fn offset(self, lines: usize, height: usize) -> usize {
+        let space = height.saturating_sub(lines);
+        match self {
+            Self::Top => 0,
+            Self::Middle => space / 2,
+            Self::Bottom => space,
+        }
+    }
+}
+
+/// Controls what happens to control characters (such as `\r`, NUL, or tabs that aren't
+/// expanded) in cells, which move the cursor in ways that can't be measured
+///
+/// Line breaks always split the cell into lines, and ANSI escape sequences are always kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ControlChars {
+    /// Leave them as they are
+    #[default]
+    Keep,
+    /// Remove them
+    Strip,
+    /// Replace them with their escaped form, such as `\r` or `\u{7}`
+    Escape,
+}
+
+/// Controls what is drawn in place of the body of a table that doesn't have any rows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmptyBehavior {
+    /// Draw a single row with this text in it, spanning every column
+    Placeholder(Cow<'static, str>),
+    /// Only draw the border, along with the header and footer if there are any, which is a box
+    /// without anything inside of it when there aren't
+    Minimal,
+}
+
+impl Default for EmptyBehavior {
+    /// Draw `(no data)` in place of the body
+    fn default() -> Self {
+        Self::Placeholder(Cow::Borrowed("(no data)"))
+    }
+}
+
+/// The ellipsis used when no other ellipsis has been set
+const DEFAULT_ELLIPSIS: &str = "…";
+
+/// A single cell of a table, along with how it should be styled
+///
+/// Cells can be added to a table using [`TableBuilder::styled_row`], or created for each value
+/// using [`BorderFormatter::format_table_with`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cell {
+    /// The contents of the cell
+    pub(crate) text: String,
+    /// The alignment of the cell, overriding the alignment of its column
+    pub(crate) alignment: Option<Alignment>,
+    /// The color of the text in the cell
+    pub(crate) color: Option<Color>,
+    /// The number of columns that the cell spans, where zero is the same as one
+    pub(crate) colspan: usize,
+    /// The number of rows that the cell spans, where zero is the same as one
+    pub(crate) rowspan: usize,
+    /// The table drawn inside the cell instead of its text
+    pub(crate) table: Option<Box<TableBuilder>>,
+    /// Whether the cell is the heading of a group of rows, see [`TableBuilder::group_by`]
+    pub(crate) section: bool,
+    /// The width that the text reported with [`CellWidth`], used instead of measuring it
+    pub(crate) width: Option<usize>,
+}
+
+impl Cell {
+    /// Create a cell containing `text` that uses the default style
+    pub fn new(text: impl Display) -> Self {
+        Self {
+            text: text.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Create a cell containing another table
+    ///
+    /// The inner table is drawn in the same style as the table that it is in, without its outer
+    /// border and title, so that its lines join up with the border of the cell.  It is stretched
+    /// to fill the cell, and it is never truncated or wrapped.  Styles that can't draw a table
+    /// inside a cell write its rows as text instead.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Cell, TableBuilder}};
+    /// let mut people = TableBuilder::new();
+    /// people.header(["Name", "Age"]).row(["Jon", "38"]).row(["Jake", "25"]);
+    ///
+    /// let table = TableBuilder::new()
+    ///     .header(["Town", "People"])
+    ///     .styled_row([Cell::new("Springfield"), Cell::table(people)])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌───────────┬────────┐
+    /// │       Town│  People│
+    /// ├───────────┼────┬───┤
+    /// │Springfield│Name│Age│
+    /// │           ├────┼───┤
+    /// │           │ Jon│ 38│
+    /// │           ├────┼───┤
+    /// │           │Jake│ 25│
+    /// └───────────┴────┴───┘
+    /// ```
+    ///
+    /// The inner table lines up with the outer one even if the borders are more than one column
+    /// wide:
+    /// ```rust
+    /// # use borderrs::{table::{Cell, TableBuilder}, StringBorderStyle};
+    /// let mut people = TableBuilder::new();
+    /// people.row(["Jon", "38"]).row(["Jake", "25"]);
+    ///
+    /// let table = TableBuilder::new()
+    ///     .header(["Town", "People"])
+    ///     .styled_row([Cell::new("Springfield"), Cell::table(people)])
+    ///     .build(&StringBorderStyle::new("||", "=", "++"));
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "\
+    /// ++===========++========++
+    /// ||       Town||  People||
+    /// ++===========++====++==++
+    /// ||Springfield|| Jon||38||
+    /// ||           ++====++==++
+    /// ||           ||Jake||25||
+    /// ++===========++====++==++"
+    /// );
+    /// ```
+    pub fn table(table: TableBuilder) -> Self {
+        Self {
+            table: Some(Box::new(table)),
+            ..Self::default()
+        }
+    }
+
+    /// Create a cell containing a bar that is `width` characters long, filled in to show how big
+    /// `value` is compared to `max`
+    ///
+    /// The bar is drawn with `█` for the filled part and `░` for the rest, with a `▓` where the
+    /// value fills at least half of the next character.  Values below zero draw an empty bar and
+    /// values above `max` draw a full one.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Cell, TableBuilder}, Alignment};
+    /// let table = TableBuilder::new()
+    ///     .header(["Task", "Progress"])
+    ///     .styled_row([Cell::new("Download"), Cell::bar(7.0, 10.0, 10)])
+    ///     .styled_row([Cell::new("Install"), Cell::bar(2.5, 10.0, 10)])
+    ///     .alignments(&[Alignment::Left, Alignment::Left])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────┬──────────┐
+    /// │Task    │Progress  │
+    /// ├────────┼──────────┤
+    /// │Download│███████░░░│
+    /// ├────────┼──────────┤
+    /// │Install │██▓░░░░░░░│
+    /// └────────┴──────────┘
+    /// ```
+    pub fn bar(value: f64, max: f64, width: usize) -> Self {
+        Self::new(chart::bar(value, max, width))
+    }
+
+    /// Create a cell containing `text` that links to `url`, which can be clicked in terminals that
+    /// support OSC 8 hyperlinks
+    ///
+    /// The link is written as escape sequences around the text, so only the text takes up any
+    /// room in the table, and terminals without hyperlinks just show the text.  Styles that don't
+    /// draw to a terminal, such as [`styles::HTML`](crate::styles::HTML), only write the text.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Cell, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Crate", "Docs"])
+    ///     .styled_row([
+    ///         Cell::new("borderrs"),
+    ///         Cell::link("docs.rs", "https://docs.rs/borderrs"),
+    ///     ])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output (with `docs.rs` linking to <https://docs.rs/borderrs>)
+    /// ```text
+    /// ┌────────┬───────┐
+    /// │   Crate│   Docs│
+    /// ├────────┼───────┤
+    /// │borderrs│docs.rs│
+    /// └────────┴───────┘
+    /// ```
+    pub fn link(text: impl Display, url: impl Display) -> Self {
+        Self::new(format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text))
+    }
+
+    /// Create a cell containing `value`, taking up the width that it reports with [`CellWidth`]
+    /// rather than the width of its text
+    ///
+    /// Every line of the text is taken to be that wide, and the text is never truncated or
+    /// wrapped, since it can't be measured to find where to cut it.  Strings, numbers, `char`s,
+    /// and `bool`s report the width of their text, so `Cell::measured(42)` is the same as
+    /// `Cell::new(42)` apart from never being cut.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Cell, CellWidth, TableBuilder}};
+    /// # use std::fmt;
+    /// /// An arrow drawn with a font that joins `->` into a single character
+    /// struct Arrow;
+    ///
+    /// impl fmt::Display for Arrow {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str("->")
+    ///     }
+    /// }
+    ///
+    /// impl CellWidth for Arrow {
+    ///     fn cell_width(&self) -> usize {
+    ///         1
+    ///     }
+    /// }
+    ///
+    /// let table = TableBuilder::new()
+    ///     .styled_row([Cell::new("a"), Cell::measured(Arrow), Cell::new("b")])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output (which lines up when `->` is drawn as one character)
+    /// ```text
+    /// ┌─┬─┬─┐
+    /// │a│->│b│
+    /// └─┴─┴─┘
+    /// ```
+    pub fn measured(value: impl CellWidth) -> Self {
+        Self {
+            width: Some(value.cell_width()),
+            ..Self::new(value)
+        }
+    }
+
+    /// Align this cell using `alignment` rather than the alignment of its column
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Draw the text in this cell in `color`
+    ///
+    /// The color is only used by styles that draw to a terminal.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Make this cell span `columns` columns, merging it with the cells to its right
+    ///
+    /// The following cells in the row start after the columns that this cell spans.  A cell can't
+    /// add columns to the table by spanning past the last one, its span is cut short instead.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Cell, TableBuilder}, Alignment};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age", "Town"])
+    ///     .styled_row([Cell::new("Staff")
+    ///         .with_colspan(3)
+    ///         .with_alignment(Alignment::Center)])
+    ///     .row(["Jon", "38", "Springfield"])
+    ///     .row(["Jake", "25", "Shelbyville"])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┬───────────┐
+    /// │Name│Age│       Town│
+    /// ├────┴───┴───────────┤
+    /// │       Staff        │
+    /// ├────┬───┬───────────┤
+    /// │ Jon│ 38│Springfield│
+    /// ├────┼───┼───────────┤
+    /// │Jake│ 25│Shelbyville│
+    /// └────┴───┴───────────┘
+    /// ```
+    ///
+    /// The borders between the spanned columns are part of the cell too, however wide they are in
+    /// the style:
+    /// ```rust
+    /// # use borderrs::{table::{Cell, TableBuilder}, StringBorderStyle};
+    /// let table = TableBuilder::new()
+    ///     .styled_row([Cell::new("Total").with_colspan(5)])
+    ///     .row(["1", "2"])
+    ///     .max_width(2)
+    ///     .build(&StringBorderStyle::new("||", "=", "++"));
+    ///
+    /// assert_eq!(table, "++=====++\n||Total||\n++=++==++\n||1|| 2||\n++=++==++");
+    /// ```
+    pub fn with_colspan(mut self, columns: usize) -> Self {
+        self.colspan = columns;
+        self
+    }
+
+    /// Make this cell span `rows` rows, merging it with the cells below it
+    ///
+    /// The rows below skip over the columns that this cell covers, so their cells start in the
+    /// next free column.  A cell can't span past the end of the header, body or footer that it is
+    /// in.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Cell, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Town", "Name", "Age"])
+    ///     .styled_row([Cell::new("Springfield").with_rowspan(2), Cell::new("Jon"), Cell::new("38")])
+    ///     .row(["Jake", "25"])
+    ///     .row(["Shelbyville", "Finn", "14"])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌───────────┬────┬───┐
+    /// │       Town│Name│Age│
+    /// ├───────────┼────┼───┤
+    /// │Springfield│ Jon│ 38│
+    /// │           ├────┼───┤
+    /// │           │Jake│ 25│
+    /// ├───────────┼────┼───┤
+    /// │Shelbyville│Finn│ 14│
+    /// └───────────┴────┴───┘
+    /// ```
+    pub fn with_rowspan(mut self, rows: usize) -> Self {
+        self.rowspan = rows;
+        self
+    }
+
+    /// Get the contents of the cell
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Whether this cell has no text and no table in it
+    fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.table.is_none()
+    }
+
+    /// Get the number of columns that this cell spans
+    fn span(&self) -> usize {
+        self.colspan.max(1)
+    }
+}
+
+/// Where a cell ends up in the grid of a table, once the cells spanning multiple columns and rows
+/// have been taken into account
+#[derive(Clone, Copy)]
+struct Slot<'a> {
+    /// The cell that starts in this column, if there is one
+    cell: Option<&'a Cell>,
+    /// The number of columns that the cell spans, or zero if the column is covered by a cell to
+    /// its left
+    span: usize,
+    /// The number of rows that the cell spans
+    rows: usize,
+    /// Whether the column is covered by a cell in a row above
+    continued: bool,
+}
+
+impl Slot<'_> {
+    /// A column without a cell, in a row that is shorter than the others
+    const EMPTY: Self = Self {
+        cell: None,
+        span: 1,
+        rows: 1,
+        continued: false,
+    };
+}
+
+/// A cell that has been split into lines, ready to be drawn by a style
+pub(crate) struct LayoutCell<'a> {
+    /// The lines of the cell, after they have been truncated or wrapped
+    pub(crate) lines: Vec<Cow<'a, str>>,
+    /// The alignment of the cell, taking the alignment of its column into account
+    pub(crate) alignment: Alignment,
+    /// Where the lines of the cell are placed in a row that is taller than the cell
+    pub(crate) vertical_alignment: VerticalAlignment,
+    /// The character that the lines of the cell are padded with
+    pub(crate) fill: char,
+    /// The color of the text in the cell
+    pub(crate) color: Option<Color>,
+    /// The color of the background of the cell
+    pub(crate) background: Option<Color>,
+    /// The number of columns that the cell spans, or zero if the column is covered by a cell to
+    /// its left
+    pub(crate) span: usize,
+    /// The number of rows that the cell spans
+    pub(crate) rows: usize,
+    /// Whether the column is covered by a cell in a row above, which holds the text instead
+    pub(crate) continued: bool,
+    /// The table drawn inside the cell instead of its lines
+    pub(crate) nested: Option<Layout<'a>>,
+    /// Whether the cell is the heading of a group of rows
+    pub(crate) section: bool,
+    /// The width of each of the lines, if it was reported with [`CellWidth`] rather than measured
+    pub(crate) measured: Option<usize>,
+}
+
+impl LayoutCell<'_> {
+    /// Create a placeholder for a column that is covered by another cell, keeping the span of
+    /// `slot`
+    fn covered(slot: &Slot) -> Self {
+        Self {
+            lines: Vec::new(),
+            alignment: Alignment::default(),
+            vertical_alignment: VerticalAlignment::default(),
+            fill: ' ',
+            color: None,
+            background: None,
+            span: slot.span,
+            rows: 1,
+            continued: slot.continued,
+            nested: None,
+            section: false,
+            measured: None,
+        }
+    }
+
+    /// Get the width of the widest line in the cell, if it has any lines
+    fn width(&self) -> Option<usize> {
+        if let Some(nested) = &self.nested {
+            return Some(nested.width());
+        }
+        self.lines.iter().map(|line| self.line_width(line)).max()
+    }
+
+    /// Get the width of `line`, which is one of the lines of the cell or a blank line above or
+    /// below them
+    pub(crate) fn line_width(&self, line: &str) -> usize {
+        match self.measured {
+            Some(width) if !line.is_empty() => width,
+            _ => width::display_width(line),
+        }
+    }
+
+    /// Get the text of `line` when the cell is drawn in a row that is `height` lines tall, taking
+    /// its vertical alignment into account
+    pub(crate) fn line(&self, line: usize, height: usize) -> &str {
+        let offset = self.vertical_alignment.offset(self.lines.len(), height);
+        line.checked_sub(offset)
+            .and_then(|line| self.lines.get(line))
+            .map_or("", |line| line.as_ref())
+    }
+
+    /// Get the number of lines that the cell takes up
+    pub(crate) fn height(&self) -> usize {
+        self.nested
+            .as_ref()
+            .map_or(self.lines.len(), Layout::height)
+    }
+}
+
+/// A table that has been split into lines and measured, ready to be drawn by a style
+pub(crate) struct Layout<'a> {
+    /// Every cell in every row (including the header), with ragged rows padded
+    pub(crate) rows: Vec<Vec<LayoutCell<'a>>>,
+    /// The width of each column
+    pub(crate) widths: Vec<usize>,
+    /// Whether the first row is the header
+    pub(crate) has_header: bool,
+    /// Whether the last row is the footer
+    pub(crate) has_footer: bool,
+    /// Which rows get a horizontal rule between them
+    pub(crate) row_separator: RowSeparator,
+    /// The number of blank lines above and below the lines of each row
+    pub(crate) row_padding: (usize, usize),
+    /// The number of columns that each border between two columns takes up
+    pub(crate) inner: usize,
+}
+
+impl Layout<'_> {
+    /// Whether `row` is the last row of the header or the body when followed by a footer, which
+    /// the rule below is drawn differently for
+    pub(crate) fn is_around_body(&self, row: usize) -> bool {
+        (self.has_header && row == 0) || (self.has_footer && row + 2 == self.rows.len())
+    }
+
+    /// Whether there is a horizontal rule between `row` and the next row
+    pub(crate) fn is_ruled_after(&self, row: usize) -> bool {
+        let separate = match self.row_separator {
+            RowSeparator::Every => true,
+            RowSeparator::HeaderOnly => self.is_around_body(row),
+            RowSeparator::Groups => {
+                let is_section = |row: usize| {
+                    let first = self.rows.get(row).and_then(|cells| cells.first());
+                    first.is_some_and(|cell| cell.section)
+                };
+                self.is_around_body(row) || is_section(row) || is_section(row + 1)
+            }
+            RowSeparator::None => false,
+        };
+        separate && row + 1 < self.rows.len()
+    }
+
+    /// Get the number of lines that each row takes up, where empty rows still take up one line
+    pub(crate) fn heights(&self) -> Vec<usize> {
+        let (above, below) = self.row_padding;
+        self.rows
+            .iter()
+            .map(|row| row.iter().map(LayoutCell::height).max().unwrap_or(0).max(1))
+            .map(|height| above + height + below)
+            .collect()
+    }
+
+    /// Get the text of `line` of `cell` when it is drawn in a row that is `height` lines tall,
+    /// taking the padding of the row and the vertical alignment of the cell into account
+    pub(crate) fn line<'c>(&self, cell: &'c LayoutCell, line: usize, height: usize) -> &'c str {
+        let (above, below) = self.row_padding;
+        let content = height.saturating_sub(above + below);
+        match line.checked_sub(above) {
+            Some(line) if line < content => cell.line(line, content),
+            _ => "",
+        }
+    }
+
+    /// Whether every cell takes up exactly one slot of the grid, so that the table has no spans
+    /// or nested tables
+    pub(crate) fn is_grid(&self) -> bool {
+        self.rows.iter().flatten().all(|cell| {
+            cell.span == 1 && cell.rows == 1 && !cell.continued && cell.nested.is_none()
+        })
+    }
+
+    /// Get the number of columns that the table takes up without its outer border
+    fn width(&self) -> usize {
+        self.widths.iter().sum::<usize>() + self.widths.len().saturating_sub(1) * self.inner
+    }
+
+    /// Get the number of lines that the table takes up without its outer border
+    pub(crate) fn height(&self) -> usize {
+        let rules = (0..self.rows.len())
+            .filter(|&row| self.is_ruled_after(row))
+            .count();
+        self.heights().iter().sum::<usize>() + rules
+    }
+}
+
+/// How many columns the vertical borders of a style take up, which the layout of a table leaves
+/// room for
+///
+/// See [`BorderFormatter::border_widths`] for more info
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderWidths {
+    /// The left and right edges together
+    pub edges: usize,
+    /// Each border between two columns
+    pub inner: usize,
+}
+
+impl BorderWidths {
+    /// Get the number of columns that the borders of a table with `cols` columns take up
+    pub(crate) fn total(self, cols: usize) -> usize {
+        self.edges + cols.saturating_sub(1) * self.inner
+    }
+}
+
+impl Default for BorderWidths {
+    /// A single column for every border
+    fn default() -> Self {
+        Self { edges: 2, inner: 1 }
+    }
+}
+
+/// How the cells of a column are combined into its footer by [`TableBuilder::aggregate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// Add up the numbers in the column
+    Sum,
+    /// Take the average of the numbers in the column
+    Mean,
+    /// Count the cells in the column that aren't empty
+    Count,
+}
+
+/// A column of a table, either by its index or by the text of its header
+///
+/// This is used by [`TableBuilder::select_columns`], and is created from a [`usize`] or a
+/// [`str`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnRef<'a> {
+    /// The column at this index, starting from zero
+    Index(usize),
+    /// The first column with this header
+    Header(&'a str),
+}
+
+impl From<usize> for ColumnRef<'_> {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl<'a> From<&'a str> for ColumnRef<'a> {
+    fn from(header: &'a str) -> Self {
+        Self::Header(header)
+    }
+}
+
+/// The direction that the rows of a table are sorted in by [`TableBuilder::sort_by_column`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// From the smallest value to the largest
+    #[default]
+    Ascending,
+    /// From the largest value to the smallest
+    Descending,
+}
+
+/// Controls which rows of a table are separated by a horizontal rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RowSeparator {
+    /// Put a rule between every row
+    #[default]
+    Every,
+    /// Only put a rule between the header and the body, and between the body and the footer
+    HeaderOnly,
+    /// Put a rule between the header and the body, between the body and the footer, and around
+    /// the heading of each group made by [`TableBuilder::group_by`]
+    Groups,
+    /// Don't put any rules between rows
+    None,
+}
+
+impl TableBuilder {
+    /// Create an empty table without a header
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the header row of the table, replacing any previous header
+    pub fn header(&mut self, headers: impl IntoIterator<Item = impl Display>) -> &mut Self {
+        self.headers = headers.into_iter().map(Cell::new).collect();
+        self
+    }
+
+    /// Set the footer row of the table, replacing any previous footer
+    ///
+    /// The footer is always drawn below the body, separated from it by the same rule as the
+    /// header, which makes it useful for totals.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Item", "Price"])
+    ///     .footer(["Total", "4.50"])
+    ///     .row(["Coffee", "3.00"])
+    ///     .row(["Cookie", "1.50"])
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌──────┬─────┐
+    /// │  Item│Price│
+    /// ├──────┼─────┤
+    /// │Coffee│ 3.00│
+    /// │Cookie│ 1.50│
+    /// ├──────┼─────┤
+    /// │ Total│ 4.50│
+    /// └──────┴─────┘
+    /// ```
+    pub fn footer(&mut self, footers: impl IntoIterator<Item = impl Display>) -> &mut Self {
+        self.footers = footers.into_iter().map(Cell::new).collect();
+        self
+    }
+
+    /// Add a row to the bottom of the table
+    pub fn row(&mut self, row: impl IntoIterator<Item = impl Display>) -> &mut Self {
+        self.styled_row(row.into_iter().map(Cell::new))
+    }
+
+    /// Add a row of cells that have their own styles to the bottom of the table
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{color::Color, styles, table::{Cell, TableBuilder}, Alignment};
+    /// let table = TableBuilder::new()
+    ///     .header(["Account", "Balance"])
+    ///     .row(["Savings", "1200"])
+    ///     .styled_row([
+    ///         Cell::new("Checking").with_alignment(Alignment::Left),
+    ///         Cell::new("-35").with_color(Color::Red),
+    ///     ])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output (with `-35` in red)
+    /// ```text
+    /// ┌────────┬───────┐
+    /// │ Account│Balance│
+    /// ├────────┼───────┤
+    /// │ Savings│   1200│
+    /// ├────────┼───────┤
+    /// │Checking│    -35│
+    /// └────────┴───────┘
+    /// ```
+    pub fn styled_row(&mut self, row: impl IntoIterator<Item = Cell>) -> &mut Self {
+        self.rows.push(row.into_iter().collect());
+        self
+    }
+
+    /// Add a row to the bottom of the table for each of the `rows`
+    pub fn rows(
+        &mut self,
+        rows: impl IntoIterator<Item = impl IntoIterator<Item = impl Display>>,
+    ) -> &mut Self {
+        for row in rows {
+            self.row(row);
+        }
+        self
+    }
+
+    /// Add a row to the bottom of the table for each of the `rows`, using `columns` to format
+    /// its cells, and set the header to the headers of the columns
+    ///
+    /// See [`Columns`] for example and more info
+    pub fn columns<T: ?Sized>(
+        &mut self,
+        columns: &Columns<'_, T>,
+        rows: impl IntoIterator<Item = impl Borrow<T>>,
+    ) -> &mut Self {
+        self.header(columns.headers());
+        for row in rows {
+            self.row(columns.cells(row.borrow()));
+        }
+        self
+    }
+
+    /// Add a two-column row to the bottom of the table for each key/value pair
+    ///
+    /// This can be used to build a table from a [`HashMap`](std::collections::HashMap) or
+    /// [`BTreeMap`](alloc::collections::BTreeMap).
+    pub fn pairs(
+        &mut self,
+        pairs: impl IntoIterator<Item = (impl Display, impl Display)>,
+    ) -> &mut Self {
+        for (key, value) in pairs {
+            self.row([key.to_string(), value.to_string()]);
+        }
+        self
+    }
+
+    /// Sort the rows that have been added so far by the cells in `column`
+    ///
+    /// Cells that are both plain decimal numbers (such as `-1.5`, but not `1e3` or `inf`) are
+    /// compared by their value, numbers come before anything else, and everything else is
+    /// compared as text.  Rows that are missing the column are
+    /// treated as if the cell was empty, and rows with equal cells keep their order.  The header
+    /// and footer aren't moved.
+    ///
+    /// Sorting moves whole rows, so cells that span multiple rows end up covering different rows.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, SortOrder, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .rows([["Jon", "38"], ["Jake", "125"], ["Josh", "9"]])
+    ///     .sort_by_column(1, SortOrder::Descending)
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┐
+    /// │Name│Age│
+    /// ├────┼───┤
+    /// │Jake│125│
+    /// │ Jon│ 38│
+    /// │Josh│  9│
+    /// └────┴───┘
+    /// ```
+    pub fn sort_by_column(&mut self, column: usize, order: SortOrder) -> &mut Self {
+        self.rows.sort_by(|a, b| {
+            let ordering = compare_cells(sort_key(a, column), sort_key(b, column));
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+        self
+    }
+
+    /// Group the rows that have been added so far by the cells in `column`, and put a heading
+    /// row that spans the whole table above each group
+    ///
+    /// The groups are sorted in the same way as [`Self::sort_by_column`], and the rows in each
+    /// group keep their order.  `column` is removed from the table since its cells are in the
+    /// headings.  Use [`RowSeparator::Groups`] to only put rules around the headings.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Team", "Score"])
+    ///     .rows([
+    ///         ["Jon", "Red", "38"],
+    ///         ["Jake", "Blue", "25"],
+    ///         ["Josh", "Red", "17"],
+    ///     ])
+    ///     .group_by(1)
+    ///     .row_separator(RowSeparator::Groups)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬─────┐
+    /// │Name│Score│
+    /// ├────┴─────┤
+    /// │Blue      │
+    /// ├────┬─────┤
+    /// │Jake│   25│
+    /// ├────┴─────┤
+    /// │Red       │
+    /// ├────┬─────┤
+    /// │ Jon│   38│
+    /// │Josh│   17│
+    /// └────┴─────┘
+    /// ```
+    pub fn group_by(&mut self, column: usize) -> &mut Self {
+        self.sort_by_column(column, SortOrder::Ascending);
+        let keys: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| sort_key(row, column).to_string())
+            .collect();
+
+        let cols = self.column_count();
+        self.select_columns((0..cols).filter(|&col| col != column));
+        let span = cols.saturating_sub(1).max(1);
+
+        let rows = core::mem::take(&mut self.rows);
+        for (i, row) in rows.into_iter().enumerate() {
+            if i == 0 || keys[i] != keys[i - 1] {
+                let mut heading = Cell::new(&keys[i])
+                    .with_colspan(span)
+                    .with_alignment(Alignment::Left);
+                heading.section = true;
+                self.rows.push(vec![heading]);
+            }
+            self.rows.push(row);
+        }
+        self
+    }
+
+    /// Only keep the rows that have been added so far for which `keep` returns `true`
+    ///
+    /// `keep` is given the cells of each row.  The header and footer are always kept.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .rows([["Jon", "38"], ["Jake", "25"], ["Josh", "17"]])
+    ///     .filter_rows(|row| row[1].text().parse::<u32>().is_ok_and(|age| age >= 18))
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┐
+    /// │Name│Age│
+    /// ├────┼───┤
+    /// │ Jon│ 38│
+    /// │Jake│ 25│
+    /// └────┴───┘
+    /// ```
+    pub fn filter_rows(&mut self, mut keep: impl FnMut(&[Cell]) -> bool) -> &mut Self {
+        self.rows.retain(|row| keep(row));
+        self
+    }
+
+    /// Only keep the given `columns`, in the order that they are given, which can be picked by
+    /// their index or by their header (see [`ColumnRef`])
+    ///
+    /// Columns that don't exist are skipped.  Everything that has been set for each column, such
+    /// as its alignment and width, moves along with it.  Cells that span multiple columns still
+    /// span the same number of columns afterwards.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age", "Town"])
+    ///     .rows([["Jon", "38", "Springfield"], ["Jake", "25", "Shelbyville"]])
+    ///     .select_columns(["Town", "Name"])
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌───────────┬────┐
+    /// │       Town│Name│
+    /// ├───────────┼────┤
+    /// │Springfield│ Jon│
+    /// │Shelbyville│Jake│
+    /// └───────────┴────┘
+    /// ```
+    pub fn select_columns<'a>(
+        &mut self,
+        columns: impl IntoIterator<Item = impl Into<ColumnRef<'a>>>,
+    ) -> &mut Self {
+        let cols = self.column_count();
+        let indices: Vec<usize> = columns
+            .into_iter()
+            .filter_map(|column| match column.into() {
+                ColumnRef::Index(index) => Some(index).filter(|&index| index < cols),
+                ColumnRef::Header(header) => self.headers.iter().position(|h| h.text == header),
+            })
+            .collect();
+
+        self.headers = select(&self.headers, &indices);
+        self.footers = select(&self.footers, &indices);
+        for row in &mut self.rows {
+            *row = select(row, &indices);
+        }
+        self.alignments = select(&self.alignments, &indices);
+        self.column_max_widths = select(&self.column_max_widths, &indices);
+        self.column_min_widths = select(&self.column_min_widths, &indices);
+        self.column_weights = select(&self.column_weights, &indices);
+        self.column_vertical_alignments = select(&self.column_vertical_alignments, &indices);
+        self.column_fills = select(&self.column_fills, &indices);
+        self
+    }
+
+    /// Combine the cells in `column` of the rows that have been added so far using `aggregate`,
+    /// and put the result in the footer below them
+    ///
+    /// The other cells of the footer are left as they are, so it can be given a label by setting
+    /// the footer first, and several columns can be aggregated.  Cells that aren't plain decimal
+    /// numbers (such as `1e3` or `inf`) are skipped by [`Aggregate::Sum`] and [`Aggregate::Mean`].
+    /// Sums have as many decimal places as the most precise number in the column, and means have
+    /// at least two.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Aggregate, RowSeparator, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Item", "Price", "Rating"])
+    ///     .rows([["Coffee", "3.5", "4"], ["Bagel", "2.25", "5"], ["Tea", "3", "3"]])
+    ///     .footer(["Total"])
+    ///     .aggregate(1, Aggregate::Sum)
+    ///     .aggregate(2, Aggregate::Mean)
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌──────┬─────┬──────┐
+    /// │  Item│Price│Rating│
+    /// ├──────┼─────┼──────┤
+    /// │Coffee│  3.5│     4│
+    /// │ Bagel│ 2.25│     5│
+    /// │   Tea│    3│     3│
+    /// ├──────┼─────┼──────┤
+    /// │ Total│ 8.75│  4.00│
+    /// └──────┴─────┴──────┘
+    /// ```
+    pub fn aggregate(&mut self, column: usize, aggregate: Aggregate) -> &mut Self {
+        let cells = self
+            .rows
+            .iter()
+            .filter_map(|row| row.get(column))
+            .map(|cell| cell.text.trim())
+            .filter(|text| !text.is_empty());
+        let numbers: Vec<_> = cells
+            .clone()
+            .filter_map(|text| Some((parse_number(text)?, decimal_places(text))))
+            .collect();
+        let decimals = numbers.iter().map(|&(_, decimals)| decimals).max();
+        let sum: f64 = numbers.iter().map(|&(number, _)| number).sum();
+
+        let result = match (aggregate, decimals) {
+            (Aggregate::Sum, Some(decimals)) => format!("{:.*}", decimals, sum),
+            (Aggregate::Mean, Some(decimals)) => {
+                format!("{:.*}", decimals.max(2), sum / numbers.len() as f64)
+            }
+            (Aggregate::Count, _) => cells.count().to_string(),
+            (_, None) => String::new(),
+        };
+
+        if self.footers.len() <= column {
+            self.footers.resize(column + 1, Cell::default());
+        }
+        self.footers[column] = Cell::new(result);
+        self
+    }
+
+    /// Number the rows that have been added so far in a new column before the others, counting up
+    /// from `start`
+    ///
+    /// If the table has a header, `header` is added to it above the numbers, and if it has a
+    /// footer, the footer is left blank below them.  Everything that has been set for each column,
+    /// such as its alignment and width, moves along with it.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .rows([["Jon", "38"], ["Jake", "25"], ["Josh", "17"]])
+    ///     .index_column(1, "#")
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌─┬────┬───┐
+    /// │#│Name│Age│
+    /// ├─┼────┼───┤
+    /// │1│ Jon│ 38│
+    /// │2│Jake│ 25│
+    /// │3│Josh│ 17│
+    /// └─┴────┴───┘
+    /// ```
+    pub fn index_column(&mut self, start: usize, header: impl Display) -> &mut Self {
+        if self.has_header() {
+            self.headers.insert(0, Cell::new(header));
+        }
+        if self.has_footer() {
+            self.footers.insert(0, Cell::default());
+        }
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            row.insert(0, Cell::new(start + i));
+        }
+
+        if !self.alignments.is_empty() {
+            self.alignments.insert(0, Alignment::default());
+        }
+        shift(&mut self.column_max_widths);
+        shift(&mut self.column_min_widths);
+        shift(&mut self.column_weights);
+        shift(&mut self.column_vertical_alignments);
+        shift(&mut self.column_fills);
+        self
+    }
+
+    /// Set the alignment of each column
+    ///
+    /// See [`BorderFormatter::format_table_aligned`] for more info.
+    pub fn alignments(&mut self, alignments: &[Alignment]) -> &mut Self {
+        self.alignments = alignments.to_vec();
+        self
+    }
+
+    /// Align every cell in the header using `alignment`, regardless of the alignment of its
+    /// column
+    ///
+    /// Cells that were given their own alignment with [`Cell::with_alignment`] keep it.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder, Alignment};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .row(["Jonathan", "38"])
+    ///     .row(["Jake", "125"])
+    ///     .alignments(&[Alignment::Left, Alignment::Right])
+    ///     .header_alignment(Alignment::Center)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────┬───┐
+    /// │  Name  │Age│
+    /// ├────────┼───┤
+    /// │Jonathan│ 38│
+    /// ├────────┼───┤
+    /// │Jake    │125│
+    /// └────────┴───┘
+    /// ```
+    pub fn header_alignment(&mut self, alignment: Alignment) -> &mut Self {
+        self.header_alignment = Some(alignment);
+        self
+    }
+
+    /// Set where the lines of every cell are placed in a row that is taller than the cell,
+    /// defaults to [`VerticalAlignment::Top`]
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{TableBuilder, VerticalAlignment}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Address"])
+    ///     .row(["Jon", "12 Main Street\nSpringfield\nUSA"])
+    ///     .vertical_alignment(VerticalAlignment::Middle)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬──────────────┐
+    /// │Name│       Address│
+    /// ├────┼──────────────┤
+    /// │    │12 Main Street│
+    /// │ Jon│   Springfield│
+    /// │    │           USA│
+    /// └────┴──────────────┘
+    /// ```
+    pub fn vertical_alignment(&mut self, alignment: VerticalAlignment) -> &mut Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Set where the lines of the cells in `column` are placed in a row that is taller than them,
+    /// overriding [`Self::vertical_alignment`]
+    pub fn column_vertical_alignment(
+        &mut self,
+        column: usize,
+        alignment: VerticalAlignment,
+    ) -> &mut Self {
+        if self.column_vertical_alignments.len() <= column {
+            self.column_vertical_alignments.resize(column + 1, None);
+        }
+        self.column_vertical_alignments[column] = Some(alignment);
+        self
+    }
+
+    /// Add `above` blank lines above and `below` blank lines below the lines of every row, which
+    /// makes dense tables easier to read
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .row(["Jon", "38"])
+    ///     .row(["Jake", "25"])
+    ///     .row_padding(1, 1)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┐
+    /// │    │   │
+    /// │Name│Age│
+    /// │    │   │
+    /// ├────┼───┤
+    /// │    │   │
+    /// │ Jon│ 38│
+    /// │    │   │
+    /// ├────┼───┤
+    /// │    │   │
+    /// │Jake│ 25│
+    /// │    │   │
+    /// └────┴───┘
+    /// ```
+    pub fn row_padding(&mut self, above: usize, below: usize) -> &mut Self {
+        self.row_padding = (above, below);
+        self
+    }
+
+    /// Pad the lines of every cell with `fill` rather than spaces, such as dots for leader lines
+    ///
+    /// `fill` should be one column wide.  Lines without any text, such as the ones below a cell
+    /// that is shorter than its row, are still padded with spaces.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder, Alignment};
+    /// let table = TableBuilder::new()
+    ///     .row(["Introduction", "1"])
+    ///     .row(["Getting started", "4"])
+    ///     .row(["Styles", "12"])
+    ///     .alignments(&[Alignment::Left])
+    ///     .column_fill(0, '.')
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌───────────────┬──┐
+    /// │Introduction...│ 1│
+    /// ├───────────────┼──┤
+    /// │Getting started│ 4│
+    /// ├───────────────┼──┤
+    /// │Styles.........│12│
+    /// └───────────────┴──┘
+    /// ```
+    pub fn fill(&mut self, fill: char) -> &mut Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Pad the lines of the cells in `column` with `fill` rather than spaces, overriding
+    /// [`Self::fill`]
+    pub fn column_fill(&mut self, column: usize, fill: char) -> &mut Self {
+        if self.column_fills.len() <= column {
+            self.column_fills.resize(column + 1, None);
+        }
+        self.column_fills[column] = Some(fill);
+        self
+    }
+
+    /// Set which rows are separated by a horizontal rule, defaults to [`RowSeparator::Every`]
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, TableBuilder}};
+    /// # use std::collections::BTreeMap;
+    /// let mut map = BTreeMap::default();
+    /// map.insert("Jon", 38);
+    /// map.insert("Jake", 25);
+    /// map.insert("Josh", 17);
+    ///
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .pairs(&map)
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┐
+    /// │Name│Age│
+    /// ├────┼───┤
+    /// │Jake│ 25│
+    /// │ Jon│ 38│
+    /// │Josh│ 17│
+    /// └────┴───┘
+    /// ```
+    pub fn row_separator(&mut self, row_separator: RowSeparator) -> &mut Self {
+        self.row_separator = row_separator;
+        self
+    }
+
+    /// Set a title to be inset into the top border of the table
+    ///
+    /// The title should be a single line.  If the table is too narrow to fit the title, the last
+    /// column is widened.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .title("Ages")
+    ///     .header(["Name", "Age"])
+    ///     .row(["Jon", "38"])
+    ///     .row(["Jake", "25"])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌─ Ages ─┐
+    /// │Name│Age│
+    /// ├────┼───┤
+    /// │ Jon│ 38│
+    /// ├────┼───┤
+    /// │Jake│ 25│
+    /// └────┴───┘
+    /// ```
+    pub fn title(&mut self, title: impl Display) -> &mut Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Set where the title is placed along the top border, defaults to [`Alignment::Left`]
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder, Alignment};
+    /// let table = TableBuilder::new()
+    ///     .title("Towns")
+    ///     .title_alignment(Alignment::Right)
+    ///     .header(["Name", "Age", "Town"])
+    ///     .row(["Jon", "38", "Springfield"])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┬─── Towns ─┐
+    /// │Name│Age│       Town│
+    /// ├────┼───┼───────────┤
+    /// │ Jon│ 38│Springfield│
+    /// └────┴───┴───────────┘
+    /// ```
+    pub fn title_alignment(&mut self, alignment: Alignment) -> &mut Self {
+        self.title_alignment = Some(alignment);
+        self
+    }
+
+    /// Set the maximum width of every column
+    ///
+    /// By default, lines that are wider than their column are truncated and end with the
+    /// ellipsis (see [`Self::ellipsis`] and [`Self::overflow`]).
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Description"])
+    ///     .row(["borderrs", "Add stylish borders around your text and datastructures"])
+    ///     .max_width(16)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────┬────────────────┐
+    /// │    Name│     Description│
+    /// ├────────┼────────────────┤
+    /// │borderrs│Add stylish bor…│
+    /// └────────┴────────────────┘
+    /// ```
+    pub fn max_width(&mut self, width: usize) -> &mut Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Set the maximum width of a single column, overriding [`Self::max_width`] for that column
+    pub fn column_max_width(&mut self, column: usize, width: usize) -> &mut Self {
+        if self.column_max_widths.len() <= column {
+            self.column_max_widths.resize(column + 1, None);
+        }
+        self.column_max_widths[column] = Some(width);
+        self
+    }
+
+    /// Set the minimum width of every column, so that columns of very short cells are still easy
+    /// to read
+    ///
+    /// Narrower columns are padded according to their alignment.  The minimum width wins over the
+    /// maximum width and [`Self::auto_fit`] if they disagree.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .header(["x", "y"])
+    ///     .row(["1", "2"])
+    ///     .min_width(5)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌─────┬─────┐
+    /// │    x│    y│
+    /// ├─────┼─────┤
+    /// │    1│    2│
+    /// └─────┴─────┘
+    /// ```
+    pub fn min_width(&mut self, width: usize) -> &mut Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    /// Set the minimum width of a single column, overriding [`Self::min_width`] for that column
+    pub fn column_min_width(&mut self, column: usize, width: usize) -> &mut Self {
+        if self.column_min_widths.len() <= column {
+            self.column_min_widths.resize(column + 1, None);
+        }
+        self.column_min_widths[column] = Some(width);
+        self
+    }
+
+    /// Set whether every column should be as wide as the widest column, which suits grids such as
+    /// calendars, game boards, and matrices
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .header(["Mon", "Tue", "Wed", "Thursday"])
+    ///     .row(["1", "2", "3", "4"])
+    ///     .uniform_width(true)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────┬────────┬────────┬────────┐
+    /// │     Mon│     Tue│     Wed│Thursday│
+    /// ├────────┼────────┼────────┼────────┤
+    /// │       1│       2│       3│       4│
+    /// └────────┴────────┴────────┴────────┘
+    /// ```
+    pub fn uniform_width(&mut self, uniform: bool) -> &mut Self {
+        self.uniform_width = uniform;
+        self
+    }
+
+    /// Set the text put at the end of lines that are truncated because they are too wide for
+    /// their column, defaults to `"…"`
+    pub fn ellipsis(&mut self, ellipsis: impl Display) -> &mut Self {
+        self.ellipsis = Some(ellipsis.to_string());
+        self
+    }
+
+    /// Set what happens to lines that are wider than the maximum width of their column, defaults
+    /// to [`Overflow::Truncate`]
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Overflow, TableBuilder}, Alignment};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Description"])
+    ///     .row(["borderrs", "Add stylish borders around your text and datastructures"])
+    ///     .max_width(16)
+    ///     .overflow(Overflow::Wrap)
+    ///     .alignments(&[Alignment::Left, Alignment::Left])
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────┬──────────────┐
+    /// │Name    │Description   │
+    /// ├────────┼──────────────┤
+    /// │borderrs│Add stylish   │
+    /// │        │borders around│
+    /// │        │your text and │
+    /// │        │datastructures│
+    /// └────────┴──────────────┘
+    /// ```
+    ///
+    /// Colors and hyperlinks that are wrapped onto several lines are ended at the end of each
+    /// line and started again on the next one, so they don't spill into the borders:
+    /// ```rust
+    /// # use borderrs::{styles, table::{Overflow, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .row(["\x1b[31mlong red text\x1b[0m", "x"])
+    ///     .max_width(8)
+    ///     .overflow(Overflow::Wrap)
+    ///     .build(&styles::THIN);
+    ///
+    /// let lines: Vec<_> = table.lines().collect();
+    /// assert_eq!(lines[1], "│\x1b[31mlong red\x1b[0m│x│");
+    /// assert_eq!(lines[2], "│    \x1b[31mtext\x1b[0m│ │");
+    /// ```
+    pub fn overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Expand tabs in cells to spaces, up to the next multiple of `width` columns, before the
+    /// cells are measured
+    ///
+    /// Without this, tabs are treated like any other control character (see
+    /// [`Self::control_chars`]).
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder, Alignment};
+    /// let table = TableBuilder::new()
+    ///     .row(["a\tb"])
+    ///     .row(["abc\tde\tf"])
+    ///     .alignments(&[Alignment::Left])
+    ///     .expand_tabs(4)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌─────────┐
+    /// │a   b    │
+    /// ├─────────┤
+    /// │abc de  f│
+    /// └─────────┘
+    /// ```
+    pub fn expand_tabs(&mut self, width: usize) -> &mut Self {
+        self.tab_width = Some(width);
+        self
+    }
+
+    /// Set what happens to control characters in cells, such as `\r` or NUL, before the cells
+    /// are measured
+    ///
+    /// They are kept by default, which breaks the borders when they are printed to a terminal.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{ControlChars, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .row(["carriage\rreturn", "bell\x07"])
+    ///     .control_chars(ControlChars::Escape)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────────────┬─────────┐
+    /// │carriage\rreturn│bell\u{7}│
+    /// └────────────────┴─────────┘
+    /// ```
+    pub fn control_chars(&mut self, control_chars: ControlChars) -> &mut Self {
+        self.control_chars = control_chars;
+        self
+    }
+
+    /// Wrap each line of a cell that contains right-to-left text (such as Arabic or Hebrew) in
+    /// Unicode bidi isolates (`U+2068` and `U+2069`)
+    ///
+    /// Terminals that apply the bidi algorithm reorder right-to-left text, which can pull the
+    /// borders and neighbouring cells along with it.  The isolates keep each line to itself, and
+    /// they don't take up any columns, so the borders still line up in terminals that don't.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .row(["Hebrew", "שלום"])
+    ///     .row(["Arabic", "مرحبا"])
+    ///     .isolate_bidi(true)
+    ///     .build(&styles::THIN);
+    ///
+    /// assert!(table.contains(" \u{2068}שלום\u{2069}│"));
+    /// assert!(table.contains("│Hebrew│"));
+    /// ```
+    pub fn isolate_bidi(&mut self, isolate: bool) -> &mut Self {
+        self.isolate_bidi = isolate;
+        self
+    }
+
+    /// Fit the whole table, including its borders, within `width` columns
+    ///
+    /// If the table is too wide, the widest columns are shrunk first and their lines are
+    /// truncated or wrapped depending on [`Self::overflow`].
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Overflow, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Description"])
+    ///     .row(["borderrs", "Add stylish borders around your text and datastructures"])
+    ///     .auto_fit(24)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────┬─────────────┐
+    /// │    Name│  Description│
+    /// ├────────┼─────────────┤
+    /// │borderrs│Add stylish …│
+    /// └────────┴─────────────┘
+    /// ```
+    ///
+    /// The borders are measured using the style that the table is drawn with, so styles with
+    /// wider borders leave less room for the text:
+    /// ```rust
+    /// # use borderrs::{table::TableBuilder, StringBorderStyle};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Description"])
+    ///     .row(["borderrs", "Add stylish borders around your text and datastructures"])
+    ///     .auto_fit(30)
+    ///     .build(&StringBorderStyle::new(" | ", "-", "-+-"));
+    ///
+    /// assert!(table.lines().all(|line| line.chars().count() == 30));
+    /// ```
+    pub fn auto_fit(&mut self, width: usize) -> &mut Self {
+        self.auto_fit = Some(width);
+        self
+    }
+
+    /// Fit the whole table within the width of the terminal, see [`Self::auto_fit`]
+    ///
+    /// If the output isn't a terminal, the table isn't changed.
+    ///
+    /// Requires the `terminal_size` feature.
+    #[cfg(feature = "terminal_size")]
+    pub fn auto_fit_terminal(&mut self) -> &mut Self {
+        if let Some((terminal_size::Width(width), _)) = terminal_size::terminal_size() {
+            self.auto_fit(width.into());
+        }
+        self
+    }
+
+    /// Make the whole table, including its borders, exactly `width` columns wide, sharing the
+    /// space between the columns according to their weights (see [`Self::column_weight`])
+    ///
+    /// Unlike [`Self::auto_fit`], narrow tables are widened as well, and the columns get their
+    /// share of the width no matter how wide their contents are.  Lines that don't fit are
+    /// truncated or wrapped depending on [`Self::overflow`].
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{Overflow, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Description"])
+    ///     .row(["borderrs", "Add stylish borders around your text and datastructures"])
+    ///     .total_width(40)
+    ///     .column_weight(0, 1)
+    ///     .column_weight(1, 3)
+    ///     .overflow(Overflow::Wrap)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌─────────┬────────────────────────────┐
+    /// │     Name│                 Description│
+    /// ├─────────┼────────────────────────────┤
+    /// │ borderrs│  Add stylish borders around│
+    /// │         │your text and datastructures│
+    /// └─────────┴────────────────────────────┘
+    /// ```
+    pub fn total_width(&mut self, width: usize) -> &mut Self {
+        self.total_width = Some(width);
+        self
+    }
+
+    /// Set the share of the width given to [`Self::total_width`] that `column` gets, relative to
+    /// the other columns
+    ///
+    /// Every column has a weight of one unless it is given another one, so by default the columns
+    /// are all the same width.  Weights that add up to 100 work like percentages.
+    pub fn column_weight(&mut self, column: usize, weight: usize) -> &mut Self {
+        if self.column_weights.len() <= column {
+            self.column_weights.resize(column + 1, None);
+        }
+        self.column_weights[column] = Some(weight);
+        self
+    }
+
+    /// Give every other row in the body a `background` color, starting with the second row
+    ///
+    /// This makes it easier to follow rows across wide tables.  The color is only used by styles
+    /// that draw to a terminal.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{color::Color, styles, table::{RowSeparator, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .rows([["Jon", "38"], ["Jake", "25"], ["Josh", "17"]])
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .zebra(Color::BrightBlack)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    pub fn zebra(&mut self, background: Color) -> &mut Self {
+        self.zebra = Some(background);
+        self
+    }
+
+    /// Only draw the first `rows` rows of the body, replacing the rest with a single row that
+    /// says how many were left out
+    ///
+    /// The rows are only left out when the table is drawn, so every row is still exported by
+    /// [`Self::to_csv`].
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, TableBuilder}};
+    /// let table = TableBuilder::new()
+    ///     .header(["Request", "Status"])
+    ///     .rows((1..=100).map(|i| [format!("#{}", i), "200".to_string()]))
+    ///     .max_rows(3)
+    ///     .row_separator(RowSeparator::HeaderOnly)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌─────────┬────────┐
+    /// │  Request│  Status│
+    /// ├─────────┼────────┤
+    /// │       #1│     200│
+    /// │       #2│     200│
+    /// │       #3│     200│
+    /// │… and 97 more rows│
+    /// └──────────────────┘
+    /// ```
+    pub fn max_rows(&mut self, rows: usize) -> &mut Self {
+        self.max_rows = Some(rows);
+        self
+    }
+
+    /// Set what is drawn in place of the body when the table doesn't have any rows, defaults to
+    /// a `(no data)` placeholder
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{EmptyBehavior, TableBuilder}};
+    /// let placeholder = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .build(&styles::THIN);
+    /// let minimal = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .empty_behavior(EmptyBehavior::Minimal)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}\n{}", placeholder, minimal);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬────┐
+    /// │Name│ Age│
+    /// ├────┴────┤
+    /// │(no data)│
+    /// └─────────┘
+    /// ┌────┬───┐
+    /// │Name│Age│
+    /// └────┴───┘
+    /// ```
+    ///
+    /// Rows that don't have any cells left, such as after [`Self::select_columns`] skips every
+    /// column they had, count as empty too, even if [`Self::max_rows`] would hide some of them:
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age"])
+    ///     .rows([["Jon", "38"], ["Jake", "25"], ["Josh", "17"], ["Jim", "52"], ["Jay", "9"]])
+    ///     .footer(["Total", "141"])
+    ///     .select_columns(["Town"])
+    ///     .max_rows(2)
+    ///     .build(&styles::THIN);
+    ///
+    /// assert_eq!(table, "┌─────────┐\n│(no data)│\n└─────────┘");
+    /// ```
+    pub fn empty_behavior(&mut self, behavior: EmptyBehavior) -> &mut Self {
+        self.empty_behavior = behavior;
+        self
+    }
+
+    /// Find the columns where every cell in the body is a number, right-align them and line
+    /// their numbers up on the decimal point, and left-align every other column
+    ///
+    /// Columns and cells that were given an alignment keep it, and the header and footer are
+    /// never treated as numbers when finding the columns.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .header(["Item", "Price"])
+    ///     .rows([["Coffee", "3.5"], ["Bagel", "12"], ["Sandwich", "8.25"]])
+    ///     .align_numbers(true)
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────────┬─────┐
+    /// │Item    │Price│
+    /// ├────────┼─────┤
+    /// │Coffee  │ 3.5 │
+    /// ├────────┼─────┤
+    /// │Bagel   │12   │
+    /// ├────────┼─────┤
+    /// │Sandwich│ 8.25│
+    /// └────────┴─────┘
+    /// ```
+    pub fn align_numbers(&mut self, align: bool) -> &mut Self {
+        self.align_numbers = align;
+        self
+    }
+
+    /// Put `separator` between each group of three digits before the decimal point of the
+    /// numbers in the columns found by [`Self::align_numbers`]
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .header(["City", "Population"])
+    ///     .rows([["Tokyo", "37400068"], ["Reykjavík", "131136"]])
+    ///     .align_numbers(true)
+    ///     .thousands_separator(',')
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌─────────┬──────────┐
+    /// │City     │Population│
+    /// ├─────────┼──────────┤
+    /// │Tokyo    │37,400,068│
+    /// ├─────────┼──────────┤
+    /// │Reykjavík│   131,136│
+    /// └─────────┴──────────┘
+    /// ```
+    pub fn thousands_separator(&mut self, separator: char) -> &mut Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Format the table using `style`
+    pub fn build(&self, style: &impl BorderFormatter) -> String {
+        write_to_string(|w| self.write(w, style))
+    }
+
+    /// Write the table into `w` using `style`
+    pub fn write(
+        &self,
+        w: &mut (impl fmt::Write + ?Sized),
+        style: &impl BorderFormatter,
+    ) -> fmt::Result {
+        style.write_table_builder(w, self)
+    }
+
+    /// Format the table using `style`, returning an error instead of producing broken output
+    ///
+    /// This fails if `style` is invalid (see [`BorderFormatter::validate`]), or if the table
+    /// itself is (see [`Self::validate`]).
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{error::BorderError, styles, table::TableBuilder};
+    /// let result = TableBuilder::new()
+    ///     .header(["Name", "Description"])
+    ///     .row(["borderrs", "Add stylish borders around your text and datastructures"])
+    ///     .auto_fit(4)
+    ///     .try_build(&styles::THIN);
+    ///
+    /// assert_eq!(result, Err(BorderError::TooNarrow { width: 4, needed: 5 }));
+    /// ```
+    ///
+    /// The width needed takes the borders of `style` into account, so a table that fits with one
+    /// column borders can still be too wide for a style with wider ones:
+    /// ```rust
+    /// # use borderrs::{error::BorderError, styles, table::TableBuilder, StringBorderStyle};
+    /// let mut table = TableBuilder::new();
+    /// table
+    ///     .row(["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n"])
+    ///     .auto_fit(30);
+    ///
+    /// assert!(table.try_build(&styles::THIN).is_ok());
+    /// assert_eq!(
+    ///     table.try_build(&StringBorderStyle::new("||", "=", "++")),
+    ///     Err(BorderError::TooNarrow { width: 30, needed: 44 }),
+    /// );
+    /// ```
+    pub fn try_build(&self, style: &impl BorderFormatter) -> Result<String, BorderError> {
+        style.validate()?;
+        self.validate_with(style.border_widths())?;
+        let mut out = String::new();
+        self.write(&mut out, style)?;
+        Ok(out)
+    }
+
+    /// Check that the table can be drawn without breaking its borders
+    ///
+    /// This fails if any text in the table contains a control character other than a line
+    /// break that isn't taken care of by [`Self::expand_tabs`] or [`Self::control_chars`], or if
+    /// the table can't fit within the width given to [`Self::auto_fit`] or
+    /// [`Self::total_width`] even with every column shrunk as far as it can go.
+    ///
+    /// Since there's no style to measure, the borders are taken to be one column wide.
+    /// [`Self::try_build`] checks the width using the borders of the style instead.
+    pub fn validate(&self) -> Result<(), BorderError> {
+        self.validate_with(BorderWidths::default())
+    }
+
+    /// Check that the table can be drawn by a style whose borders are as wide as `borders`
+    ///
+    /// See [`Self::validate`] for more info
+    pub(crate) fn validate_with(&self, borders: BorderWidths) -> Result<(), BorderError> {
+        let cells = iter::once(&self.headers)
+            .chain(&self.rows)
+            .chain(iter::once(&self.footers))
+            .flatten();
+        for cell in cells {
+            for line in cell.text.lines() {
+                error::check_text(&self.sanitize(line))?;
+            }
+            if let Some(table) = &cell.table {
+                table.validate_with(borders)?;
+            }
+        }
+        for text in self.title.iter().chain(&self.ellipsis) {
+            error::check_text(text)?;
+        }
+        for &fill in self.fill.iter().chain(self.column_fills.iter().flatten()) {
+            if fill.is_control() {
+                return Err(BorderError::ControlCharacter(fill));
+            }
+        }
+        if let EmptyBehavior::Placeholder(text) = &self.empty_behavior {
+            error::check_text(text)?;
+        }
+
+        if let Some(width) = self.total_width.or(self.auto_fit) {
+            // Every column is at least one wide, with a border on either side of it
+            let cols = self.slots().first().map_or(0, Vec::len);
+            let needed = (0..cols)
+                .map(|col| self.column_min_width_of(col).unwrap_or(1).max(1))
+                .sum::<usize>()
+                + borders.total(cols);
+            if width < needed {
+                return Err(BorderError::TooNarrow { width, needed });
+            }
+        }
+        Ok(())
+    }
+
+    /// Get a copy of the table with its rows and columns swapped, so that the header becomes the
+    /// first column and each row becomes a column
+    ///
+    /// This fits wide tables with only a few rows into narrow terminals better.  Cells that span
+    /// several columns span the same number of rows instead, and the other way around.  The
+    /// settings for specific columns, such as their alignments and widths, are left out since the
+    /// columns are different, but the rest of the settings are kept.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::TableBuilder};
+    /// let table = TableBuilder::new()
+    ///     .header(["Name", "Age", "Town"])
+    ///     .row(["Jon", "38", "Springfield"])
+    ///     .row(["Jake", "25", "Shelbyville"])
+    ///     .transposed()
+    ///     .build(&styles::THIN);
+    ///
+    /// println!("{}", table);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───────────┬───────────┐
+    /// │Name│        Jon│       Jake│
+    /// ├────┼───────────┼───────────┤
+    /// │ Age│         38│         25│
+    /// ├────┼───────────┼───────────┤
+    /// │Town│Springfield│Shelbyville│
+    /// └────┴───────────┴───────────┘
+    /// ```
+    pub fn transposed(&self) -> TableBuilder {
+        let slots = self.slots();
+        let cols = slots.first().map_or(0, Vec::len);
+        let rows = (0..cols)
+            .map(|col| {
+                slots
+                    .iter()
+                    .map(|row| &row[col])
+                    .filter(|slot| !slot.continued && slot.span > 0)
+                    .map(|slot| Cell {
+                        colspan: slot.rows,
+                        rowspan: slot.span,
+                        ..slot.cell.cloned().unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        TableBuilder {
+            headers: Vec::new(),
+            rows,
+            footers: Vec::new(),
+            alignments: Vec::new(),
+            header_alignment: None,
+            column_max_widths: Vec::new(),
+            column_min_widths: Vec::new(),
+            column_weights: Vec::new(),
+            column_vertical_alignments: Vec::new(),
+            column_fills: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Split the body of the table into pages of `rows_per_page` rows, and format each of them
+    /// using `style`
+    ///
+    /// Every page repeats the title and the header, and the footer is only on the last page.  The
+    /// columns are as wide on every page as they are in the whole table, so the pages line up
+    /// when they are printed one after another.  A table without any rows still has one page.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::{styles, table::{RowSeparator, TableBuilder}};
+    /// let mut table = TableBuilder::new();
+    /// table
+    ///     .header(["Name", "Age"])
+    ///     .rows([["Jon", "38"], ["Jake", "25"], ["Josh", "17"]])
+    ///     .row_separator(RowSeparator::HeaderOnly);
+    ///
+    /// for page in table.paginate(2, &styles::THIN) {
+    ///     println!("{}", page);
+    /// }
+    /// ```
+    /// produces the output
+    /// ```text
+    /// ┌────┬───┐
+    /// │Name│Age│
+    /// ├────┼───┤
+    /// │ Jon│ 38│
+    /// │Jake│ 25│
+    /// └────┴───┘
+    /// ┌────┬───┐
+    /// │Name│Age│
+    /// ├────┼───┤
+    /// │Josh│ 17│
+    /// └────┴───┘
+    /// ```
+    pub fn paginate<'a, S: BorderFormatter>(
+        &'a self,
+        rows_per_page: usize,
+        style: &'a S,
+    ) -> impl Iterator<Item = String> + 'a {
+        let rows_per_page = rows_per_page.max(1);
+        let pages = self.rows.len().div_ceil(rows_per_page).max(1);
+
+        let mut page = TableBuilder {
+            rows: Vec::new(),
+            footers: Vec::new(),
+            ..self.clone()
+        };
+        for (column, &width) in self.layout(style.border_widths()).widths.iter().enumerate() {
+            page.column_min_width(column, width);
+        }
+
+        self.rows
+            .chunks(rows_per_page)
+            .chain(self.rows.is_empty().then_some(&[][..]))
+            .enumerate()
+            .map(move |(i, rows)| {
+                let mut page = page.clone();
+                page.rows = rows.to_vec();
+                if i + 1 == pages {
+                    page.footers = self.footers.clone();
+                }
+                page.build(style)
+            })
+    }
+
+    /// Export the table as comma-separated values
+    ///
+    /// Unlike the styles, this uses the raw contents of the table, so nothing is truncated or
+    /// wrapped.  Fields that contain a comma, a quote or a line break are quoted (doubling any
+    /// quotes inside them), and short rows are padded with empty fields.
+    ///
+    /// For example,
+    /// ```rust
+    /// # use borderrs::table::TableBuilder;
+    /// let csv = TableBuilder::new()
+    ///     .header(["Name", "Quote"])
+    ///     .row(["Jon", "Hello, world"])
+    ///     .row(["Jake", "He said \"hi\""])
+    ///     .to_csv();
+    ///
+    /// print!("{}", csv);
+    /// ```
+    /// produces the output
+    /// ```text
+    /// Name,Quote
+    /// Jon,"Hello, world"
+    /// Jake,"He said ""hi"""
+    /// ```
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
+
+    /// Export the table as tab-separated values
+    ///
+    /// Fields are quoted in the same way as [`Self::to_csv`], but only if they contain a tab, a
+    /// quote or a line break.
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
+
+    /// Export the table with each field separated by `delimiter` and each record on its own line
+    fn to_delimited(&self, delimiter: char) -> String {
+        let mut out = String::new();
+        for record in self.slots() {
+            for (col, slot) in record.iter().enumerate() {
+                if col > 0 {
+                    out.push(delimiter);
+                }
+                // Cells that span several columns or rows leave the fields they cover empty,
+                // and nested tables are written as a record of their own
+                let field = match slot.cell {
+                    Some(cell) if !slot.continued && slot.span > 0 => match &cell.table {
+                        Some(table) => Cow::Owned(table.to_delimited(delimiter).trim_end().into()),
+                        None => Cow::Borrowed(cell.text.as_str()),
+                    },
+                    _ => Cow::Borrowed(""),
+                };
+                if field.contains([delimiter, '"', '\n', '\r']) {
+                    out.push('"');
+                    out.push_str(&field.replace('"', "\"\""));
+                    out.push('"');
+                } else {
+                    out.push_str(&field);
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Expand the tabs in a line of a cell and take care of its control characters, according to
+    /// [`Self::expand_tabs`] and [`Self::control_chars`]
+    fn sanitize<'a>(&self, line: &'a str) -> Cow<'a, str> {
+        width::sanitize(line, self.tab_width, self.control_chars)
+    }
+
+    /// Get the maximum width of `column`, if it has one
+    fn column_max_width_of(&self, column: usize) -> Option<usize> {
+        self.column_max_widths
+            .get(column)
+            .copied()
+            .flatten()
+            .or(self.max_width)
+    }
+
+    /// Split the space inside of a table that is `total` columns wide between `cols` columns,
+    /// proportionally to their weights
+    ///
+    /// Every column is at least one wide, and the columns left over after rounding down go to the
+    /// columns that lost the most to rounding.  If every weight is zero, the columns share the
+    /// width equally.
+    fn share_width(&self, total: usize, cols: usize, borders: BorderWidths) -> Vec<usize> {
+        if cols == 0 {
+            return Vec::new();
+        }
+        let available = total.saturating_sub(borders.total(cols));
+        let mut weights: Vec<_> = (0..cols)
+            .map(|col| self.column_weights.get(col).copied().flatten().unwrap_or(1))
+            .collect();
+        if weights.iter().all(|&weight| weight == 0) {
+            weights.fill(1);
+        }
+        let sum = weights.iter().sum::<usize>();
+        let mut widths: Vec<_> = weights.iter().map(|w| available * w / sum).collect();
+
+        let mut rest = available - widths.iter().sum::<usize>();
+        let mut order: Vec<_> = (0..cols).collect();
+        order.sort_by_key(|&col| core::cmp::Reverse(available * weights[col] % sum));
+        // Rounding down loses less than one column for each column
+        for &col in order.iter().take(rest) {
+            widths[col] += 1;
+            rest -= 1;
+        }
+        debug_assert_eq!(rest, 0);
+        widths.iter_mut().for_each(|width| *width = (*width).max(1));
+        widths
+    }
+
+    /// Get the minimum width of `column`, if it has one
+    fn column_min_width_of(&self, column: usize) -> Option<usize> {
+        self.column_min_widths
+            .get(column)
+            .copied()
+            .flatten()
+            .or(self.min_width)
+    }
+
+    /// Get the title of the table along with where it is placed, if it has one
+    pub(crate) fn title_with_alignment(&self) -> Option<(&str, Alignment)> {
+        let alignment = self.title_alignment.unwrap_or(Alignment::Left);
+        self.title.as_deref().map(|title| (title, alignment))
+    }
+
+    /// Whether the table has a header
+    pub(crate) fn has_header(&self) -> bool {
+        self.headers.iter().any(|h| !h.is_empty())
+    }
+
+    /// Get the number of columns in the longest row, including the header and footer
+    fn column_count(&self) -> usize {
+        iter::once(&self.headers)
+            .chain(&self.rows)
+            .chain(iter::once(&self.footers))
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Whether the table has a footer
+    pub(crate) fn has_footer(&self) -> bool {
+        self.footers.iter().any(|f| !f.is_empty())
+    }
+
+    /// Place the cells of the header, body and footer in the grid of the table, padding ragged
+    /// rows with empty slots
+    fn slots(&self) -> Vec<Vec<Slot<'_>>> {
+        let header = self.has_header().then(|| place(iter::once(&self.headers)));
+        let footer = self.has_footer().then(|| place(iter::once(&self.footers)));
+        let mut slots: Vec<_> = header
+            .into_iter()
+            .chain(iter::once(place(&self.rows)))
+            .chain(footer)
+            .flatten()
+            .collect();
+
+        // The row that reaches furthest determines the number of columns, where a cell that spans
+        // several columns only reaches its first one, so that a span can't add columns of its own
+        let cols = slots
+            .iter()
+            .filter_map(|row| row.iter().rposition(|slot| slot.cell.is_some()))
+            .map(|col| col + 1)
+            .max()
+            .unwrap_or(0);
+        for row in &mut slots {
+            row.resize(cols, Slot::EMPTY);
+            for (col, slot) in row.iter_mut().enumerate() {
+                slot.span = slot.span.min(cols - col);
+            }
+        }
+        slots
+    }
+
+    /// Split every cell into its lines, truncating or wrapping lines that are wider than the
+    /// column's limit
+    fn split_lines<'a>(
+        &self,
+        slots: &[Vec<Slot<'a>>],
+        limits: &[Option<usize>],
+        borders: BorderWidths,
+    ) -> Vec<Vec<LayoutCell<'a>>> {
+        let numbers = self.number_columns(slots);
+        let mut lines: Vec<_> = slots
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let header = i == 0 && self.has_header();
+                row.iter()
+                    .enumerate()
+                    .map(|(col, slot)| {
+                        if slot.continued || slot.span == 0 {
+                            return LayoutCell::covered(slot);
+                        }
+
+                        // A cell that spans several columns can use the borders between them too
+                        let limit = limits[col..col + slot.span]
+                            .iter()
+                            .copied()
+                            .sum::<Option<usize>>();
+                        let limit = limit.map(|limit| limit + (slot.span - 1) * borders.inner);
+                        self.split_cell(slot, col, header, numbers[col], limit, borders)
+                    })
+                    .collect()
+            })
+            .collect();
+        // A body without any cells gets the placeholder however many rows it has, so it is never
+        // summarized as well
+        match self.placeholder() {
+            Some(placeholder) => self.fill_empty(&mut lines, placeholder, limits, borders),
+            None => self.summarize_rows(&mut lines, limits, borders),
+        }
+        lines
+    }
+
+    /// Replace the rows of the body past [`Self::max_rows`] with a row that says how many of
+    /// them there were, fitting it within `limits` and the `borders` between them
+    fn summarize_rows(
+        &self,
+        lines: &mut Vec<Vec<LayoutCell<'_>>>,
+        limits: &[Option<usize>],
+        borders: BorderWidths,
+    ) {
+        let Some(max) = self.max_rows else {
+            return;
+        };
+        let hidden = self.rows.len().saturating_sub(max);
+        if hidden == 0 {
+            return;
+        }
+        let start = usize::from(self.has_header());
+        let end = start + max;
+
+        // Cells in the rows that are kept can't span into the rows that are left out
+        for (i, row) in lines[start..end].iter_mut().enumerate() {
+            for cell in row {
+                cell.rows = cell.rows.min(max - i);
+            }
+        }
+
+        let text = match hidden {
+            1 => "… and 1 more row".to_string(),
+            _ => format!("… and {} more rows", hidden),
+        };
+        let summary = self.spanning_row(text, limits, borders);
+        lines.splice(end..end + hidden, summary);
+    }
+
+    /// Replace the rows of the body with a row holding `placeholder`, see [`Self::placeholder`]
+    fn fill_empty(
+        &self,
+        lines: &mut Vec<Vec<LayoutCell<'_>>>,
+        placeholder: &str,
+        limits: &[Option<usize>],
+        borders: BorderWidths,
+    ) {
+        let start = usize::from(self.has_header());
+        let end = lines.len() - usize::from(self.has_footer());
+        let row = self.spanning_row(placeholder.to_string(), limits, borders);
+        lines.splice(start..end, row);
+    }
+
+    /// Get the placeholder to draw in place of the body, if it is empty and should have one
+    fn placeholder(&self) -> Option<&str> {
+        match &self.empty_behavior {
+            EmptyBehavior::Placeholder(text) if self.rows.iter().all(Vec::is_empty) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Create a left-aligned row with a single cell containing `text` that spans every column,
+    /// truncating it to fit within `limits` and the `borders` between them, or nothing if there
+    /// aren't any columns
+    fn spanning_row(
+        &self,
+        text: String,
+        limits: &[Option<usize>],
+        borders: BorderWidths,
+    ) -> Option<Vec<LayoutCell<'static>>> {
+        let cols = limits.len();
+        if cols == 0 {
+            return None;
+        }
+        let limit = limits.iter().copied().sum::<Option<usize>>();
+        let ellipsis = self.ellipsis.as_deref().unwrap_or(DEFAULT_ELLIPSIS);
+        let text = match limit {
+            Some(limit) => {
+                width::truncate(&text, limit + (cols - 1) * borders.inner, ellipsis).into_owned()
+            }
+            None => text,
+        };
+        let mut row: Vec<_> = (0..cols)
+            .map(|_| LayoutCell {
+                lines: Vec::new(),
+                alignment: Alignment::Left,
+                vertical_alignment: VerticalAlignment::default(),
+                fill: ' ',
+                color: None,
+                background: None,
+                span: 0,
+                rows: 1,
+                continued: false,
+                nested: None,
+                section: false,
+                measured: None,
+            })
+            .collect();
+        row[0].lines = vec![Cow::Owned(text)];
+        row[0].span = cols;
+        Some(row)
+    }
+
+    /// Split the cell in `slot`, which is in column `col` (and the header if `header` is set),
+    /// into its lines, truncating or wrapping lines that are wider than `limit`
+    ///
+    /// `fraction` is the width of the widest fraction in the column if it is a column of
+    /// numbers, see [`Self::number_columns`].
+    fn split_cell<'a>(
+        &self,
+        slot: &Slot<'a>,
+        col: usize,
+        header: bool,
+        fraction: Option<usize>,
+        limit: Option<usize>,
+        borders: BorderWidths,
+    ) -> LayoutCell<'a> {
+        let cell = slot.cell;
+        let nested = cell
+            .and_then(|c| c.table.as_deref())
+            .map(|table| table.layout(borders));
+        let ellipsis = self.ellipsis.as_deref().unwrap_or(DEFAULT_ELLIPSIS);
+        let number = fraction
+            .filter(|_| !header)
+            .zip(cell)
+            .and_then(|(fraction, c)| self.format_number(&c.text, fraction));
+        let lines = cell.map(|c| c.text.lines()).into_iter().flatten();
+        let lines = lines.map(|line| self.sanitize(line));
+        // Text that reported its own width can't be measured to find where to cut it
+        let limit = limit.filter(|_| cell.and_then(|c| c.width).is_none());
+        let lines = match (number, limit, self.overflow) {
+            (Some(number), Some(max), _) => {
+                vec![Cow::Owned(
+                    width::truncate(&number, max, ellipsis).into_owned(),
+                )]
+            }
+            (Some(number), None, _) => vec![Cow::Owned(number)],
+            (None, Some(max), Overflow::Truncate) => lines
+                .map(|line| match line {
+                    Cow::Borrowed(line) => width::truncate(line, max, ellipsis),
+                    Cow::Owned(line) => {
+                        Cow::Owned(width::truncate(&line, max, ellipsis).into_owned())
+                    }
+                })
+                .collect(),
+            (None, Some(max), Overflow::Wrap) => lines
+                .flat_map(|line| width::wrap(&line, max))
+                .map(Cow::Owned)
+                .collect(),
+            (None, None, _) => lines.collect(),
+        };
+        let lines = match self.isolate_bidi {
+            true => lines.into_iter().map(width::isolate_bidi).collect(),
+            false => lines,
+        };
+        let column_alignment = self.alignments.get(col).copied();
+        let header_alignment = self.header_alignment.filter(|_| header);
+        let number_alignment = self.align_numbers.then_some(match fraction {
+            Some(_) => Alignment::Right,
+            None => Alignment::Left,
+        });
+        LayoutCell {
+            lines,
+            alignment: cell
+                .and_then(|c| c.alignment)
+                .or(header_alignment)
+                .or(column_alignment)
+                .or(number_alignment)
+                .unwrap_or_default(),
+            vertical_alignment: self
+                .column_vertical_alignments
+                .get(col)
+                .copied()
+                .flatten()
+                .unwrap_or(self.vertical_alignment),
+            fill: self
+                .column_fills
+                .get(col)
+                .copied()
+                .flatten()
+                .or(self.fill)
+                .unwrap_or(' '),
+            color: cell.and_then(|c| c.color),
+            background: None,
+            span: slot.span,
+            rows: slot.rows,
+            continued: false,
+            nested,
+            section: cell.is_some_and(|c| c.section),
+            measured: cell.and_then(|c| c.width),
+        }
+    }
+
+    /// Find the columns where every cell in the body is a number when [`Self::align_numbers`] is
+    /// set, giving the width of the widest fraction (including its decimal point) in each of them
+    fn number_columns(&self, slots: &[Vec<Slot<'_>>]) -> Vec<Option<usize>> {
+        let cols = slots.first().map_or(0, Vec::len);
+        if !self.align_numbers {
+            return vec![None; cols];
+        }
+        let start = usize::from(self.has_header());
+        let end = slots.len() - usize::from(self.has_footer());
+        let body = &slots[start..end.max(start)];
+        (0..cols)
+            .map(|col| {
+                let texts = body.iter().filter_map(|row| {
+                    let slot = &row[col];
+                    let cell = slot.cell.filter(|_| slot.span == 1 && !slot.continued)?;
+                    Some(cell.text.trim()).filter(|text| !text.is_empty())
+                });
+                let mut fraction = None;
+                for text in texts {
+                    if !is_number(text) {
+                        return None;
+                    }
+                    fraction = fraction.max(Some(fraction_width(text)));
+                }
+                fraction
+            })
+            .collect()
+    }
+
+    /// Format `text` if it is a number, adding the thousands separator and padding the end so
+    /// that its fraction is `fraction` columns wide
+    fn format_number(&self, text: &str, fraction: usize) -> Option<String> {
+        let text = text.trim();
+        if !is_number(text) {
+            return None;
+        }
+        let (integer, rest) = text.split_at(text.len() - fraction_width(text));
+        let mut out = String::with_capacity(text.len() + fraction);
+        match self.thousands_separator {
+            Some(separator) => {
+                let digits = integer.trim_start_matches(['-', '+']);
+                out.push_str(&integer[..integer.len() - digits.len()]);
+                for (i, digit) in digits.chars().enumerate() {
+                    if i > 0 && (digits.len() - i) % 3 == 0 {
+                        out.push(separator);
+                    }
+                    out.push(digit);
+                }
+            }
+            None => out.push_str(integer),
+        }
+        out.push_str(rest);
+        out.extend(iter::repeat_n(' ', fraction.saturating_sub(rest.len())));
+        Some(out)
+    }
+
+    /// Get a copy of the table without any limits on its width, including the tables nested inside
+    /// of it, for styles that don't need the columns to fit within a width
+    pub(crate) fn unlimited(&self) -> TableBuilder {
+        let mut table = TableBuilder {
+            max_width: None,
+            column_max_widths: Vec::new(),
+            auto_fit: None,
+            total_width: None,
+            ..self.clone()
+        };
+        let cells = table
+            .headers
+            .iter_mut()
+            .chain(table.rows.iter_mut().flatten())
+            .chain(table.footers.iter_mut());
+        for cell in cells {
+            if let Some(nested) = &mut cell.table {
+                **nested = nested.unlimited();
+            }
+        }
+        table
+    }
+
+    /// Split the table into lines and measure each column so that it can be drawn by a style
+    /// whose borders are as wide as `borders`
+    pub(crate) fn layout(&self, borders: BorderWidths) -> Layout<'_> {
+        // The header goes on the top of the table and the footer on the bottom, if they are
+        // provided
+        let has_header = self.has_header();
+        let has_footer = self.has_footer();
+        let mut rows = self.slots();
+        let mut cols = rows.first().map_or(0, Vec::len);
+        // The placeholder for an empty table needs a column to go in
+        if cols == 0 && self.placeholder().is_some() {
+            cols = 1;
+            rows.iter_mut()
+                .for_each(|row| row.resize(cols, Slot::EMPTY));
+        }
+
+        let limits: Vec<_> = (0..cols).map(|i| self.column_max_width_of(i)).collect();
+        let mut lines = self.split_lines(&rows, &limits, borders);
+        let mut widths = measure(&lines, cols, borders);
+
+        // Share the width between the columns, then split the lines again using their widths
+        if let Some(total) = self.total_width {
+            let shares = self.share_width(total, cols, borders);
+            let limits: Vec<_> = shares.iter().map(|&width| Some(width)).collect();
+            lines = self.split_lines(&rows, &limits, borders);
+            widths = measure(&lines, cols, borders);
+            // Tables nested in cells are never shrunk, so they can be wider than their share
+            for (width, share) in widths.iter_mut().zip(shares) {
+                *width = (*width).max(share);
+            }
+        } else if let Some(total) = self.auto_fit {
+            // Shrink the columns to fit, then split the lines again using the new widths
+            if shrink_to_fit(&mut widths, total, borders) {
+                let limits: Vec<_> = widths.iter().map(|&width| Some(width)).collect();
+                lines = self.split_lines(&rows, &limits, borders);
+                widths = measure(&lines, cols, borders);
+            }
+        }
+
+        for (col, width) in widths.iter_mut().enumerate() {
+            if let Some(min) = self.column_min_width_of(col) {
+                *width = (*width).max(min);
+            }
+        }
+
+        // Make every column as wide as the widest one
+        if self.uniform_width {
+            let max = widths.iter().copied().max().unwrap_or(0);
+            widths.iter_mut().for_each(|width| *width = max);
+        }
+
+        if let Some(title) = &self.title {
+            fit_title(&mut widths, title, borders);
+        }
+
+        // Stripe every other row of the body, starting with the second one
+        if let Some(background) = self.zebra {
+            let body_len = lines.len() - usize::from(has_header) - usize::from(has_footer);
+            let body = lines
+                .iter_mut()
+                .skip(usize::from(has_header))
+                .take(body_len);
+            for row in body.skip(1).step_by(2) {
+                row.iter_mut()
+                    .filter(|cell| !cell.continued)
+                    .for_each(|cell| cell.background = Some(background));
+            }
+        }
+
+        Layout {
+            rows: lines,
+            widths,
+            has_header,
+            has_footer,
+            row_separator: self.row_separator,
+            row_padding: self.row_padding,
+            inner: borders.inner,
+        }
+    }
+}
+
+/// Pick the values at `indices` out of `values` for [`TableBuilder::select_columns`], using the
+/// default for indices past the end, unless there aren't any values at all
+fn select<T: Clone + Default>(values: &[T], indices: &[usize]) -> Vec<T> {
+    if values.is_empty() {
+        return Vec::new();
+    }
+    indices
+        .iter()
+        .map(|&index| values.get(index).cloned().unwrap_or_default())
+        .collect()
+}
+
+/// Move the settings for each column one column to the right for
+/// [`TableBuilder::index_column`], leaving the new first column unset
+fn shift<T>(values: &mut Vec<Option<T>>) {
+    if !values.is_empty() {
+        values.insert(0, None);
+    }
+}
+
+/// Get the text of the cell in `column` of `row` that it is sorted by
+fn sort_key(row: &[Cell], column: usize) -> &str {
+    row.get(column).map_or("", |cell| cell.text.trim())
+}
+
+/// Compare two cells for [`TableBuilder::sort_by_column`], by value if they are both numbers
+/// and as text otherwise, with numbers coming first
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (parse_number(a), parse_number(b)) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.cmp(b),
+    }
+}
+
+/// Get the value of `text` if it is a plain decimal number (see [`is_number`])
+///
+/// Numbers too large to be represented are treated as text, so the value is always finite.
+fn parse_number(text: &str) -> Option<f64> {
+    if !is_number(text) {
+        return None;
+    }
+    text.parse().ok().filter(|number: &f64| number.is_finite())
+}
+
+/// The number of digits after the decimal point of `number`
+fn decimal_places(number: &str) -> usize {
+    number.split_once('.').map_or(0, |(_, fraction)| {
+        fraction.bytes().take_while(u8::is_ascii_digit).count()
+    })
+}
+
+/// Whether `text` is a plain decimal number, with an optional sign and decimal point
+fn is_number(text: &str) -> bool {
+    let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    !(integer.is_empty() && fraction.is_empty()) && all_digits(integer) && all_digits(fraction)
+}
+
+/// The width of the fraction of a number, including its decimal point
+fn fraction_width(number: &str) -> usize {
+    number.find('.').map_or(0, |dot| number.len() - dot)
+}
+
+/// Place each of the `rows` in the grid of a table, skipping over the columns that are covered
+/// by cells in the rows above
+///
+/// Cells that span several columns are cut short if they would run into a column that is
+/// covered, and cells that span several rows are cut short at the last row.
+fn place<'a>(rows: impl IntoIterator<Item = &'a Vec<Cell>>) -> Vec<Vec<Slot<'a>>> {
+    let rows: Vec<_> = rows.into_iter().collect();
+    // The number of rows below the current one that each column is still covered for, along with
+    // the span of the covering cell in its first column (and zero in the others)
+    let mut pending: Vec<(usize, usize)> = Vec::new();
+
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut cells = row.iter();
+            let mut slots = Vec::new();
+            loop {
+                let col = slots.len();
+                if let Some((remaining, span)) = pending.get_mut(col).filter(|(r, _)| *r > 0) {
+                    *remaining -= 1;
+                    slots.push(Slot {
+                        cell: None,
+                        span: *span,
+                        rows: 1,
+                        continued: true,
+                    });
+                    continue;
+                }
+
+                let Some(cell) = cells.next() else {
+                    // Keep going while there are still columns covered from above
+                    if pending[col.min(pending.len())..]
+                        .iter()
+                        .any(|(r, _)| *r > 0)
+                    {
+                        slots.push(Slot::EMPTY);
+                        continue;
+                    }
+                    break;
+                };
+
+                let blocked = pending
+                    .get(col..)
+                    .and_then(|rest| rest.iter().position(|(r, _)| *r > 0));
+                let span = blocked.map_or(cell.span(), |free| cell.span().min(free));
+                let below = cell.rowspan.max(1).min(rows.len() - i) - 1;
+                if below > 0 {
+                    if pending.len() < col + span {
+                        pending.resize(col + span, (0, 0));
+                    }
+                    pending[col] = (below, span);
+                    pending[col + 1..col + span].fill((below, 0));
+                }
+
+                slots.push(Slot {
+                    cell: Some(cell),
+                    span,
+                    rows: below + 1,
+                    continued: false,
+                });
+                slots.extend((1..span).map(|_| Slot {
+                    span: 0,
+                    ..Slot::EMPTY
+                }));
+            }
+            slots
+        })
+        .collect()
+}
+
+/// Get the width of each column using its widest line
+///
+/// Cells that span several columns are measured afterwards, widening the columns they span if
+/// they don't fit in them along with the `borders` between them.
+fn measure(rows: &[Vec<LayoutCell>], cols: usize, borders: BorderWidths) -> Vec<usize> {
+    let mut widths: Vec<usize> = (0..cols)
+        .map(|i| {
+            rows.iter()
+                .filter(|row| row[i].span == 1)
+                .flat_map(|row| row[i].width())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for row in rows {
+        for (i, cell) in row.iter().enumerate().filter(|(_, cell)| cell.span > 1) {
+            let needed = cell.width().unwrap_or(0);
+            let spanned = &mut widths[i..i + cell.span];
+            let available = spanned.iter().sum::<usize>() + (cell.span - 1) * borders.inner;
+
+            // Share the extra width between the columns, giving any remainder to the last ones
+            let extra = needed.saturating_sub(available);
+            let count = spanned.len();
+            for (j, width) in spanned.iter_mut().enumerate() {
+                *width += extra / count + usize::from(j >= count - extra % count);
+            }
+        }
+    }
+    widths
+}
+
+/// Shrink the widest columns one at a time until the table, including its borders, fits within
+/// `total` columns
+///
+/// Columns are never shrunk below a width of one.  Returns whether any column was shrunk.
+fn shrink_to_fit(widths: &mut [usize], total: usize, borders: BorderWidths) -> bool {
+    let borders = borders.total(widths.len());
+    let mut excess = (widths.iter().sum::<usize>() + borders).saturating_sub(total);
+    let mut shrunk = false;
+    while excess > 0 {
+        match widths.iter_mut().max() {
+            Some(widest) if *widest > 1 => {
+                *widest -= 1;
+                excess -= 1;
+                shrunk = true;
+            }
+            _ => break,
+        }
+    }
+    shrunk
+}
+
+/// Widen the last column so that `title` fits inside the top line, with a space and at least one
+/// horizontal line on either side of it
+fn fit_title(widths: &mut Vec<usize>, title: &str, borders: BorderWidths) {
+    let needed = width::display_width(title) + 4;
+    let available = widths.iter().sum::<usize>() + widths.len().saturating_sub(1) * borders.inner;
+    if needed > available {
+        match widths.last_mut() {
+            Some(last) => *last += needed - available,
+            None => widths.push(needed),
+        }
+    }
+}
+
+/// Used to turn a value into a row of a table, with headers describing each cell
+///
+/// With the `derive` feature enabled, this can be derived for structs to use the field names as
+/// the headers.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, table::ToRow, BorderFormatter};
+/// struct Person {
+///     name: &'static str,
+///     age: u32,
+/// }
+///
+/// impl ToRow for Person {
+///     fn headers() -> Vec<String> {
+///         vec!["name".into(), "age".into()]
+///     }
+///
+///     fn to_row(&self) -> Vec<String> {
+///         vec![self.name.to_string(), self.age.to_string()]
+///     }
+/// }
+///
+/// let people = [
+///     Person { name: "Jon", age: 38 },
+///     Person { name: "Jake", age: 25 },
+/// ];
+/// println!("{}", styles::THIN.format_rows(&people));
+/// ```
+/// or, using the derive macro,
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// # use borderrs::{styles, table::ToRow, BorderFormatter};
+/// #[derive(ToRow)]
+/// struct Person {
+///     name: &'static str,
+///     #[row(rename = "Age")]
+///     age: u32,
+///     #[row(skip)]
+///     id: u64,
+/// }
+///
+/// let people = [
+///     Person { name: "Jon", age: 38, id: 1 },
+///     Person { name: "Jake", age: 25, id: 2 },
+/// ];
+/// println!("{}", styles::THIN.format_rows(&people));
+/// # }
+/// ```
+/// both produce the output
+/// ```text
+/// ┌────┬───┐
+/// │name│Age│
+/// ├────┼───┤
+/// │ Jon│ 38│
+/// ├────┼───┤
+/// │Jake│ 25│
+/// └────┴───┘
+/// ```
+pub trait ToRow {
+    /// The headers for each of the cells returned by [`Self::to_row`]
+    fn headers() -> Vec<String>;
+
+    /// Get the cells for this value's row
+    fn to_row(&self) -> Vec<String>;
+}
+
+/// The columns of a table of typed rows, each with a header and a function that formats its
+/// cell from a row
+///
+/// Unlike [`ToRow`], each column can be formatted however it needs to be without having to turn
+/// every row into strings up front, and different tables can show the same rows differently.
+///
+/// For example,
+/// ```rust
+/// # use borderrs::{styles, table::{Columns, TableBuilder}};
+/// struct Reading {
+///     sensor: &'static str,
+///     celsius: f64,
+///     ok: bool,
+/// }
+///
+/// let readings = [
+///     Reading { sensor: "kitchen", celsius: 21.4567, ok: true },
+///     Reading { sensor: "attic", celsius: 38.1, ok: false },
+/// ];
+/// let columns = Columns::new()
+///     .column("Sensor", |r: &Reading| r.sensor.to_string())
+///     .column("Temp", |r| format!("{:.1} °C", r.celsius))
+///     .column("Status", |r| if r.ok { "ok" } else { "check" }.to_string());
+///
+/// let table = TableBuilder::new()
+///     .columns(&columns, &readings)
+///     .build(&styles::THIN);
+/// println!("{}", table);
+/// ```
+/// produces the output
+/// ```text
+/// ┌───────┬───────┬──────┐
+/// │ Sensor│   Temp│Status│
+/// ├───────┼───────┼──────┤
+/// │kitchen│21.5 °C│    ok│
+/// ├───────┼───────┼──────┤
+/// │  attic│38.1 °C│ check│
+/// └───────┴───────┴──────┘
+/// ```
+pub struct Columns<'a, T: ?Sized> {
+    /// The header and formatter of each column
+    columns: Vec<(String, CellFormatter<'a, T>)>,
+}
+
+/// Formats the cell of a column from a row
+type CellFormatter<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+impl<'a, T: ?Sized> Columns<'a, T> {
+    /// Create an empty set of columns
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Add a column to the right of the others, with `header` above it and `format` used to get
+    /// its cell from each row
+    pub fn column(mut self, header: impl Display, format: impl Fn(&T) -> String + 'a) -> Self {
+        self.columns.push((header.to_string(), Box::new(format)));
+        self
+    }
+
+    /// Get the headers of the columns
+    fn headers(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().map(|(header, _)| header.as_str())
+    }
+
+    /// Get the cells of `row`, one for each column
+    fn cells<'b>(&'b self, row: &'b T) -> impl Iterator<Item = String> + 'b {
+        self.columns.iter().map(move |(_, format)| format(row))
+    }
+}
+
+impl<T: ?Sized> Default for Columns<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Columns<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.headers()).finish()
+    }
+}
+
+/// A value that may be missing, such as an [`Option`] or a [`Result`], which is drawn as a
+/// placeholder when it is
+///
+/// This is used by [`BorderFormatter::format_slice_opt`] and
+/// [`BorderFormatter::format_table_opt`], so that missing values don't have to be converted
+/// before they are formatted.
+pub trait OptionalCell {
+    /// Get the value to draw, or `None` if the placeholder should be drawn instead
+    fn value(&self) -> Option<&dyn Display>;
+}
+
+impl<T: Display> OptionalCell for Option<T> {
+    fn value(&self) -> Option<&dyn Display> {
+        self.as_ref().map(|value| value as &dyn Display)
+    }
+}
+
+impl<T: Display, E> OptionalCell for Result<T, E> {
+    fn value(&self) -> Option<&dyn Display> {
+        self.as_ref().ok().map(|value| value as &dyn Display)
+    }
+}
+
+impl<T: OptionalCell + ?Sized> OptionalCell for &T {
+    fn value(&self) -> Option<&dyn Display> {
+        (**self).value()
+    }
+}
+
+/// A value that knows how many columns it takes up when it is drawn
+///
+/// The default implementation measures the display width of the widest line of the text, skipping
+/// ANSI escape sequences, which is what every cell uses unless it is created with
+/// [`Cell::measured`].  Types that are drawn narrower or wider than their text, such as text for a
+/// font with ligatures or escape sequences that aren't recognized, can report their width
+/// instead.
+pub trait CellWidth: Display {
+    /// Get the number of columns that the value takes up
+    fn cell_width(&self) -> usize {
+        self.to_string()
+            .lines()
+            .map(width::display_width)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Implement [`CellWidth`] for each of the given types, measuring their text
+macro_rules! impl_cell_width {
+    ($($ty:ty),* $(,)?) => {$(
+        impl CellWidth for $ty {}
+    )*};
+}
+
+impl_cell_width!(
+    str,
+    String,
+    Cow<'_, str>,
+    char,
+    bool,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+);
+
+impl<T: CellWidth + ?Sized> CellWidth for &T {
+    fn cell_width(&self) -> usize {
+        (**self).cell_width()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{styles, StringBorderStyle};
+
+    /// Get the width of each line of `text`
+    fn line_widths(text: &str) -> Vec<usize> {
+        text.lines().map(width::display_width).collect()
+    }
+
+    #[test]
+    fn placeholder_replaces_summarized_body() {
+        let table = TableBuilder::new()
+            .header(["Name", "Age"])
+            .rows([["Jon", "38"], ["Jake", "25"], ["Josh", "17"]])
+            .footer(["Total", "80"])
+            .select_columns(["Town"])
+            .max_rows(2)
+            .build(&styles::THIN);
+        assert_eq!(table, "┌─────────┐\n│(no data)│\n└─────────┘");
+
+        let table = TableBuilder::new()
+            .header(["a", "b"])
+            .rows(vec![Vec::<String>::new(); 3])
+            .footer(["x", "y"])
+            .max_rows(2)
+            .build(&styles::THIN);
+        assert!(table.contains("(no data)"));
+        assert!(table.contains("x│   y│"));
+    }
+
+    #[test]
+    fn total_width_without_columns_or_weights() {
+        let table = TableBuilder::new()
+            .empty_behavior(EmptyBehavior::Minimal)
+            .total_width(20)
+            .build(&styles::THIN);
+        assert_eq!(table, "┌┐\n└┘");
+
+        let table = TableBuilder::new()
+            .row(["a", "b"])
+            .column_weight(0, 0)
+            .column_weight(1, 0)
+            .total_width(20)
+            .build(&styles::THIN);
+        assert!(line_widths(&table).iter().all(|&width| width == 20));
+    }
+
+    #[test]
+    fn widths_leave_room_for_wide_borders() {
+        let style = StringBorderStyle::new("||", "=", "++");
+        let mut table = TableBuilder::new();
+        table.row(["a", "bb", "ccc", "dddd"]).total_width(30);
+        assert!(line_widths(&table.build(&style)).iter().all(|&w| w == 30));
+
+        table.auto_fit(8).total_width = None;
+        assert_eq!(
+            table.try_build(&style),
+            Err(BorderError::TooNarrow {
+                width: 8,
+                needed: 14
+            })
+        );
+    }
+
+    #[test]
+    fn colspan_past_the_last_column() {
+        let table = TableBuilder::new()
+            .styled_row([Cell::new("A").with_colspan(5)])
+            .row(["1", "2"])
+            .build(&styles::THIN);
+        assert_eq!(table, "┌───┐\n│  A│\n├─┬─┤\n│1│2│\n└─┴─┘");
+    }
+
+    #[test]
+    fn summary_spans_wide_borders() {
+        let table = TableBuilder::new()
+            .rows([["1234", "5678"], ["a", "b"], ["c", "d"]])
+            .max_width(10)
+            .max_rows(1)
+            .build(&StringBorderStyle::new("||", "=", "++"));
+        assert!(table.contains("||… and 2 more rows||"));
+    }
+
+    #[test]
+    fn only_decimal_numbers_are_sorted_by_value() {
+        let mut table = TableBuilder::new();
+        table
+            .rows([["Zed"], ["Nan"], ["inf"], ["Amy"], ["2"], ["-1.5"]])
+            .sort_by_column(0, SortOrder::Ascending);
+        let order: Vec<_> = table.rows.iter().map(|row| row[0].text.as_str()).collect();
+        assert_eq!(order, ["-1.5", "2", "Amy", "Nan", "Zed", "inf"]);
+    }
+
+    #[test]
+    fn aggregate_skips_infinity() {
+        let mut table = TableBuilder::new();
+        table
+            .rows([["1.5"], ["infinity"], ["NaN"], ["2"]])
+            .aggregate(0, Aggregate::Sum);
+        assert_eq!(table.footers[0].text, "3.5");
+    }
+}
@@ -0,0 +1,129 @@
+//! This module hosts the [`TreeNode`] trait, which is used to draw hierarchical data as a tree
+//! with [`BorderFormatter::format_tree`](crate::BorderFormatter::format_tree).
+//!
+//! The intended usage is as follows:
+//! ```rust
+//! use borderrs::{styles::THIN, tree::TreeNode, BorderFormatter};
+//!
+//! struct Dir {
+//!     name: &'static str,
+//!     entries: Vec<Dir>,
+//! }
+//!
+//! impl TreeNode for Dir {
+//!     fn label(&self) -> String {
+//!         self.name.to_string()
+//!     }
+//!
+//!     fn children(&self) -> Vec<&Self> {
+//!         self.entries.iter().collect()
+//!     }
+//! }
+//!
+//! let src = Dir {
+//!     name: "src",
+//!     entries: vec![
+//!         Dir { name: "lib.rs", entries: vec![] },
+//!         Dir { name: "table.rs", entries: vec![] },
+//!     ],
+//! };
+//! println!("{}", THIN.format_tree(&src));
+//! ```
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+
+/// A node of a tree that can be drawn with
+/// [`BorderFormatter::format_tree`](crate::BorderFormatter::format_tree)
+pub trait TreeNode {
+    /// Get the text shown for this node
+    fn label(&self) -> String;
+
+    /// Get the children of this node, in the order that they are drawn
+    fn children(&self) -> Vec<&Self>;
+}
+
+/// The guides drawn in front of each node of a tree to connect it to its parent
+pub(crate) struct Guides {
+    /// In front of a node that has more siblings below it
+    pub(crate) branch: String,
+    /// In front of the last child of its parent
+    pub(crate) last: String,
+    /// In front of the descendants of a node that has more siblings below it
+    pub(crate) pipe: String,
+    /// In front of the descendants of the last child of its parent
+    pub(crate) blank: String,
+}
+
+impl Guides {
+    /// Create guides from each of their pieces
+    pub(crate) fn new(branch: &str, last: &str, pipe: &str, blank: &str) -> Self {
+        Self {
+            branch: branch.to_string(),
+            last: last.to_string(),
+            pipe: pipe.to_string(),
+            blank: blank.to_string(),
+        }
+    }
+}
+
+/// Write the tree under `root` into `w`, with `guides` connecting each node to its parent
+///
+/// Labels that span several lines have the rest of their lines lined up with the first one.
+pub(crate) fn write_tree<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+    w: &mut (impl fmt::Write + ?Sized),
+    root: &'a T,
+    label: &impl Fn(&'a T) -> L,
+    children: &impl Fn(&'a T) -> C,
+    guides: &Guides,
+) -> fmt::Result {
+    for (i, line) in lines(&label(root).to_string()).enumerate() {
+        if i > 0 {
+            w.write_char('\n')?;
+        }
+        w.write_str(line)?;
+    }
+    write_children(w, root, label, children, guides, &mut String::new())
+}
+
+/// Write the descendants of `node` into `w`, with `prefix` in front of each of its children
+fn write_children<'a, T: ?Sized, L: Display, C: IntoIterator<Item = &'a T>>(
+    w: &mut (impl fmt::Write + ?Sized),
+    node: &'a T,
+    label: &impl Fn(&'a T) -> L,
+    children: &impl Fn(&'a T) -> C,
+    guides: &Guides,
+    prefix: &mut String,
+) -> fmt::Result {
+    let nodes: Vec<_> = children(node).into_iter().collect();
+    for (i, &child) in nodes.iter().enumerate() {
+        let last = i + 1 == nodes.len();
+        let (guide, indent) = match last {
+            true => (&guides.last, &guides.blank),
+            false => (&guides.branch, &guides.pipe),
+        };
+
+        let len = prefix.len();
+        for (j, line) in lines(&label(child).to_string()).enumerate() {
+            w.write_char('\n')?;
+            w.write_str(prefix)?;
+            match j {
+                0 => w.write_str(guide)?,
+                _ => w.write_str(indent)?,
+            }
+            w.write_str(line)?;
+        }
+
+        prefix.push_str(indent);
+        write_children(w, child, label, children, guides, prefix)?;
+        prefix.truncate(len);
+    }
+    Ok(())
+}
+
+/// Split `text` into lines, where empty text is still a single empty line
+fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().chain(text.is_empty().then_some(""))
+}
@@ -0,0 +1,344 @@
+//! Measuring how many terminal columns a piece of text takes up
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::table::ControlChars;
+
+/// The escape character that starts every ANSI escape sequence
+const ESC: char = '\x1b';
+/// The bell character, which can terminate an OSC sequence
+const BEL: char = '\x07';
+
+/// A piece of text that is either entirely visible or entirely an ANSI escape sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment<'a> {
+    /// Visible text
+    Text(&'a str),
+    /// An escape sequence that doesn't take up any columns
+    Escape(&'a str),
+}
+
+/// Split `text` into runs of visible text and ANSI escape sequences
+///
+/// This handles CSI sequences (`ESC [ ... final`, used for colors and cursor movement), OSC
+/// sequences (`ESC ] ... BEL` or `ESC ] ... ESC \`, used for titles and hyperlinks) and two
+/// character escapes (`ESC x`).
+pub(crate) fn segments(text: &str) -> impl Iterator<Item = Segment<'_>> {
+    let mut rest = text;
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        if !rest.starts_with(ESC) {
+            let end = rest.find(ESC).unwrap_or(rest.len());
+            let (text, tail) = rest.split_at(end);
+            rest = tail;
+            return Some(Segment::Text(text));
+        }
+
+        let mut chars = rest.char_indices().skip(1).peekable();
+        let end = match chars.next() {
+            // CSI: parameters and intermediates until a final byte in `@..=~`
+            Some((_, '[')) => chars
+                .find(|(_, c)| ('@'..='~').contains(c))
+                .map(|(i, c)| i + c.len_utf8()),
+            // OSC: everything until BEL or the string terminator (`ESC \`)
+            Some((_, ']')) => loop {
+                match chars.next() {
+                    Some((i, BEL)) => break Some(i + 1),
+                    Some((_, ESC)) if matches!(chars.peek(), Some((_, '\\'))) => {
+                        break chars.next().map(|(i, _)| i + 1)
+                    }
+                    Some(_) => {}
+                    None => break None,
+                }
+            },
+            // Any other escape is just `ESC` followed by a single character
+            Some((i, c)) => Some(i + c.len_utf8()),
+            None => None,
+        }
+        // An unterminated escape takes up the rest of the text
+        .unwrap_or(rest.len());
+
+        let (escape, tail) = rest.split_at(end);
+        rest = tail;
+        Some(Segment::Escape(escape))
+    })
+}
+
+/// Remove all ANSI escape sequences from `text`, leaving only the visible characters
+pub(crate) fn strip_ansi(text: &str) -> String {
+    segments(text)
+        .filter_map(|segment| match segment {
+            Segment::Text(text) => Some(text),
+            Segment::Escape(_) => None,
+        })
+        .collect()
+}
+
+/// Expand the tabs in `line` to the next multiple of `tab_width` columns if it is set, then
+/// keep, strip, or escape the rest of the control characters according to `control`
+///
+/// Control characters inside of ANSI escape sequences are always kept.
+pub(crate) fn sanitize(
+    line: &str,
+    tab_width: Option<usize>,
+    control: ControlChars,
+) -> Cow<'_, str> {
+    let has_control = segments(line).any(|segment| match segment {
+        Segment::Text(text) => text.chars().any(char::is_control),
+        Segment::Escape(_) => false,
+    });
+    if !has_control || (tab_width.is_none() && control == ControlChars::Keep) {
+        return Cow::Borrowed(line);
+    }
+
+    let mut out = String::with_capacity(line.len());
+    let mut used: usize = 0;
+    for segment in segments(line) {
+        let text = match segment {
+            Segment::Escape(escape) => {
+                out.push_str(escape);
+                continue;
+            }
+            Segment::Text(text) => text,
+        };
+        for c in text.chars() {
+            match (c, tab_width, control) {
+                ('\t', Some(tab_width), _) => {
+                    let spaces = used.checked_rem(tab_width).map_or(0, |rem| tab_width - rem);
+                    out.extend(core::iter::repeat_n(' ', spaces));
+                    used += spaces;
+                }
+                (c, _, ControlChars::Strip) if c.is_control() => {}
+                (c, _, ControlChars::Escape) if c.is_control() => {
+                    let escaped = c.escape_debug();
+                    used += escaped.len();
+                    out.extend(escaped);
+                }
+                (c, _, _) => {
+                    used += c.width().unwrap_or(0);
+                    out.push(c);
+                }
+            }
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Whether `c` is from a script that is written from right to left, or is an explicit
+/// right-to-left mark
+fn is_rtl(c: char) -> bool {
+    matches!(
+        c,
+        // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic, and their extensions
+        '\u{0590}'..='\u{08FF}'
+            // Right-to-left mark, embedding, and override
+            | '\u{200F}'
+            | '\u{202B}'
+            | '\u{202E}'
+            // Hebrew and Arabic presentation forms
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}'
+            // Historic right-to-left scripts and Arabic mathematical symbols
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}'
+    )
+}
+
+/// Wrap `line` in a first strong isolate and a pop directional isolate if it contains any
+/// right-to-left text, so that a terminal applying the bidi algorithm doesn't reorder anything
+/// around it
+///
+/// The isolates are zero-width, so they don't change the width of the line.
+pub(crate) fn isolate_bidi(line: Cow<'_, str>) -> Cow<'_, str> {
+    match line.chars().any(is_rtl) {
+        true => Cow::Owned(format!("\u{2068}{}\u{2069}", line)),
+        false => line,
+    }
+}
+
+/// Get the number of columns that `text` takes up when printed, ignoring any ANSI escape
+/// sequences
+///
+/// Full-width characters (such as CJK characters and most emoji) take up two columns, while
+/// zero-width characters (such as combining marks) don't take up any.
+pub(crate) fn display_width(text: &str) -> usize {
+    strip_ansi(text).width()
+}
+
+/// Shorten `text` so that it takes up at most `width` columns, ending it with `ellipsis` if
+/// anything had to be removed
+///
+/// Escape sequences are kept even if the text around them is removed, so colors that are reset
+/// at the end of the text are still reset.  The text is only ever cut between grapheme clusters,
+/// so emoji sequences and characters with combining marks are never split apart.
+pub(crate) fn truncate<'a>(text: &'a str, width: usize, ellipsis: &str) -> Cow<'a, str> {
+    if display_width(text) <= width {
+        return Cow::Borrowed(text);
+    }
+
+    // Leave room for the ellipsis, unless there isn't enough room for it at all
+    let ellipsis = if display_width(ellipsis) <= width {
+        ellipsis
+    } else {
+        ""
+    };
+    let budget = width - display_width(ellipsis);
+
+    let mut out = String::with_capacity(text.len());
+    let mut used = 0;
+    let mut full = false;
+    for segment in segments(text) {
+        match segment {
+            Segment::Escape(escape) => out.push_str(escape),
+            Segment::Text(_) if full => {}
+            Segment::Text(text) => {
+                for grapheme in text.graphemes(true) {
+                    let w = grapheme.width();
+                    if used + w > budget {
+                        full = true;
+                        break;
+                    }
+                    used += w;
+                    out.push_str(grapheme);
+                }
+            }
+        }
+    }
+    out.push_str(ellipsis);
+    Cow::Owned(out)
+}
+
+/// Split `text` into the longest prefix that fits in `width` columns and the remainder
+///
+/// The text is only split between grapheme clusters, and at least one of them is always put into
+/// the prefix so that splitting repeatedly always makes progress.
+pub(crate) fn split_at_width(text: &str, width: usize) -> (&str, &str) {
+    let mut used = 0;
+    let mut offset = 0;
+    for segment in segments(text) {
+        match segment {
+            Segment::Escape(escape) => offset += escape.len(),
+            Segment::Text(visible) => {
+                for grapheme in visible.graphemes(true) {
+                    let w = grapheme.width();
+                    if used + w > width && used > 0 {
+                        return text.split_at(offset);
+                    }
+                    used += w;
+                    offset += grapheme.len();
+                }
+            }
+        }
+    }
+    (text, "")
+}
+
+/// Wrap `text` so that each line takes up at most `width` columns
+///
+/// Lines are broken between words where possible, and words that are too wide to fit on a line
+/// of their own are broken wherever they need to be.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    wrap_with_hyphen(text, width, "")
+}
+
+/// Wrap `text` like [`wrap`], ending each piece of a word that had to be broken up with `hyphen`
+///
+/// The hyphen is left out if the lines are too narrow to fit it along with part of the word.
+pub(crate) fn wrap_with_hyphen(text: &str, width: usize, hyphen: &str) -> Vec<String> {
+    let width = width.max(1);
+    let hyphen = if display_width(hyphen) < width {
+        hyphen
+    } else {
+        ""
+    };
+    let hyphen_width = display_width(hyphen);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut used = 0;
+    for word in text.split(' ') {
+        let mut word = word;
+        let mut word_width = display_width(word);
+
+        // Put the word on the current line if there's room for it and a space
+        if used > 0 && used + 1 + word_width <= width {
+            line.push(' ');
+            line.push_str(word);
+            used += 1 + word_width;
+            continue;
+        }
+
+        if used > 0 {
+            lines.push(core::mem::take(&mut line));
+        }
+
+        // Break up words that are too wide for a line of their own
+        while word_width > width {
+            let (head, tail) = split_at_width(word, width - hyphen_width);
+            lines.push(head.to_string() + hyphen);
+            word = tail;
+            word_width = display_width(word);
+        }
+        line.push_str(word);
+        used = word_width;
+    }
+    lines.push(line);
+    carry_escapes(&mut lines);
+    lines
+}
+
+/// Make each of `lines` stand on its own, by resetting the colors and closing the hyperlink that
+/// are still active at the end of a line, and starting them again at the start of the next one
+///
+/// Otherwise the colors of a line that was wrapped would bleed into the borders and the cells
+/// drawn after it.
+fn carry_escapes(lines: &mut [String]) {
+    // The SGR sequences since the last reset, and the sequence that opened the current hyperlink
+    let mut styles: Vec<String> = Vec::new();
+    let mut link: Option<String> = None;
+    for line in lines {
+        let mut out: String = styles.concat();
+        out.extend(link.as_deref());
+        for segment in segments(line) {
+            match segment {
+                Segment::Text(text) => out.push_str(text),
+                Segment::Escape(escape) => {
+                    out.push_str(escape);
+                    if let Some(params) = escape
+                        .strip_prefix("\x1b[")
+                        .and_then(|rest| rest.strip_suffix('m'))
+                    {
+                        match params {
+                            "" | "0" => styles.clear(),
+                            _ => styles.push(escape.to_string()),
+                        }
+                    } else if let Some(params) = escape.strip_prefix("\x1b]8;") {
+                        // The URI comes after the parameters, and an empty one closes the link
+                        let uri = params.split_once(';').map_or("", |(_, uri)| uri);
+                        let uri = uri
+                            .strip_suffix(BEL)
+                            .or_else(|| uri.strip_suffix("\x1b\\"))
+                            .unwrap_or(uri);
+                        link = (!uri.is_empty()).then(|| escape.to_string());
+                    }
+                }
+            }
+        }
+        if !styles.is_empty() {
+            out.push_str("\x1b[0m");
+        }
+        if link.is_some() {
+            out.push_str("\x1b]8;;\x1b\\");
+        }
+        *line = out;
+    }
+}